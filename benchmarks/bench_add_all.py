@@ -0,0 +1,44 @@
+"""
+Benchmark: inserting a large KotList into another KotMutableList.
+
+add_all() and add_all_at() copy a KotList or KotSet source straight from its backing store when its
+element type matches, skipping the per-element type check. Passing the same elements through a plain
+iterator takes the general path, which checks every element as both methods used to.
+
+Run from the repository root:
+    python benchmarks/bench_add_all.py
+"""
+
+import os
+import sys
+import timeit
+
+sys.path.insert(0, os.path.join(os.path.dirname(os.path.abspath(__file__)), os.pardir))
+
+from kotcollections import KotList, KotMutableList  # noqa: E402
+
+SIZE = 100_000
+REPEAT = 20
+
+
+def _time(insert) -> float:
+    """Return the average time in milliseconds of inserting into a fresh two-element list."""
+
+    def run() -> None:
+        insert(KotMutableList([-1, -2]))
+
+    return timeit.timeit(run, number=REPEAT) / REPEAT * 1000
+
+
+def main() -> None:
+    source = KotList(range(SIZE))
+
+    print(f"Inserting a {SIZE:,}-element KotList (average of {REPEAT} runs):")
+    print(f"  add_all(KotList)           {_time(lambda target: target.add_all(source)):8.2f}ms")
+    print(f"  add_all(iterator)          {_time(lambda target: target.add_all(iter(source))):8.2f}ms")
+    print(f"  add_all_at(1, KotList)     {_time(lambda target: target.add_all_at(1, source)):8.2f}ms")
+    print(f"  add_all_at(1, iterator)    {_time(lambda target: target.add_all_at(1, iter(source))):8.2f}ms")
+
+
+if __name__ == "__main__":
+    main()
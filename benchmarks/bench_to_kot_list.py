@@ -0,0 +1,38 @@
+"""
+Benchmark: cloning a large KotList with to_kot_list().
+
+to_kot_list() shares the backing list of a read-only KotList, so cloning is O(1). For comparison,
+KotList(lst) builds a new list and type-checks every element, which is what to_kot_list() used to cost.
+
+Run from the repository root:
+    python benchmarks/bench_to_kot_list.py
+"""
+
+import os
+import sys
+import timeit
+
+sys.path.insert(0, os.path.join(os.path.dirname(os.path.abspath(__file__)), os.pardir))
+
+from kotcollections import KotList, KotMutableList  # noqa: E402
+
+SIZE = 1_000_000
+CLONES = 100
+
+
+def main() -> None:
+    lst = KotList(range(SIZE))
+    mutable = KotMutableList(range(SIZE))
+
+    shared = timeit.timeit(lst.to_kot_list, number=CLONES)
+    copied = timeit.timeit(lambda: KotList(lst), number=CLONES)
+    mutable_copied = timeit.timeit(mutable.to_kot_list, number=CLONES)
+
+    print(f"Cloning a {SIZE:,}-element list {CLONES} times:")
+    print(f"  KotList.to_kot_list() (shared storage):  {shared * 1000:10.1f}ms")
+    print(f"  KotList(lst) (copy and type check):      {copied * 1000:10.1f}ms")
+    print(f"  KotMutableList.to_kot_list() (copy):     {mutable_copied * 1000:10.1f}ms")
+
+
+if __name__ == "__main__":
+    main()
@@ -1,4 +1,9 @@
-from typing import TypeVar, Generic, Callable, Dict, List, Optional
+from typing import TypeVar, Generic, Callable, Dict, List, Optional, Tuple, Union, TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from kotcollections.kot_list import KotList
+    from kotcollections.kot_map import KotMap
+    from kotcollections.kot_mutable_map import KotMutableMap
 
 T = TypeVar('T')
 K = TypeVar('K')
@@ -109,6 +114,36 @@ class KotGrouping(Generic[T, K]):
 
         return KotMap(results)
 
+    def fold_with_count(
+        self,
+        initial_value_selector: Callable[[K, T], R],
+        operation: Callable[[K, R, T], R]
+    ) -> 'KotMap[K, Tuple[R, int]]':
+        """Like fold(), but pairs each group's result with the number of elements in the group.
+
+        Both values are computed in a single pass over the source.
+
+        Returns:
+            A KotMap associating the key of each group with an (accumulator, count) tuple.
+
+        Examples:
+            >>> lst = KotList([1, 2, 3, 4, 5])
+            >>> result = lst.grouping_by(lambda x: x % 2).fold_with_count(
+            ...     lambda k, e: 0, lambda k, acc, e: acc + e
+            ... )
+            >>> result.get(1)  # Returns (9, 3)
+        """
+        from kotcollections.kot_map import KotMap
+        results: Dict[K, Tuple[R, int]] = {}
+        for element in self._source:
+            key = self._key_selector(element)
+            if key in results:
+                accumulator, count = results[key]
+            else:
+                accumulator, count = initial_value_selector(key, element), 0
+            results[key] = (operation(key, accumulator, element), count + 1)
+        return KotMap(results)
+
     def reduce(self, operation: Callable[[K, T, T], T]) -> 'KotMap[K, T]':
         """Groups elements from the Grouping source by key and applies the reducing operation
         to the elements of each group sequentially, starting from the second element of the group,
@@ -153,6 +188,27 @@ class KotGrouping(Generic[T, K]):
 
         return KotMap(results)
 
+    def reduce_indexed(self, operation: Callable[[K, int, T, T], T]) -> 'KotMap[K, T]':
+        """Like reduce(), but also passes the index of the element within its group.
+
+        The operation is invoked as operation(key, index, accumulator, element) for each element of a
+        group except the first, so index starts at 1. A group with a single element never invokes the
+        operation and maps to that element untouched.
+
+        Examples:
+            >>> lst = KotList(["a", "b", "c"])
+            >>> lst.grouping_by(lambda s: 0).reduce_indexed(lambda k, i, acc, e: f"{acc}{i}{e}")
+            >>> # Returns KotMap({0: 'a1b2c'})
+        """
+        from kotcollections.kot_map import KotMap
+        results = {}
+        for key, elements in self._group_by_key().items():
+            accumulator = elements[0]
+            for index in range(1, len(elements)):
+                accumulator = operation(key, index, accumulator, elements[index])
+            results[key] = accumulator
+        return KotMap(results)
+
     def aggregate(
         self,
         operation: Callable[[K, Optional[R], T, bool], R]
@@ -199,5 +255,104 @@ class KotGrouping(Generic[T, K]):
 
         return KotMap(results)
 
+    def aggregate_to(
+        self,
+        destination: Union[Dict[K, R], 'KotMutableMap[K, R]'],
+        operation: Callable[[K, Optional[R], T, bool], R]
+    ) -> Union[Dict[K, R], 'KotMutableMap[K, R]']:
+        """Groups elements from the Grouping source by key and applies operation to the elements of each group
+        sequentially, passing the previously accumulated value and the current element as arguments,
+        and stores the results in the given destination map.
+
+        A value already stored in the destination for a key is used as that group's accumulator.
+
+        Args:
+            destination: A KotMutableMap or a plain Python dict receiving the results
+            operation: A function invoked on each element with the key, the current accumulator
+                      (None if the destination has no value for the key yet), the element,
+                      and whether it is the first element for the key
+
+        Returns:
+            The destination map.
+
+        Examples:
+            >>> lst = KotList(["apple", "apricot", "banana"])
+            >>> result = lst.grouping_by(lambda s: s[0]).aggregate_to(
+            ...     {}, lambda k, acc, e, first: len(e) if first else acc + len(e)
+            ... )
+            >>> result  # Returns {'a': 12, 'b': 6}
+        """
+        is_dict = isinstance(destination, dict)
+        for element in self._source:
+            key = self._key_selector(element)
+            first = key not in destination if is_dict else not destination.contains_key(key)
+            accumulator = destination.get(key)
+            value = operation(key, accumulator, element, first)
+            if is_dict:
+                destination[key] = value
+            else:
+                destination.put(key, value)
+        return destination
+
+    def fold_to(
+        self,
+        destination: Union[Dict[K, R], 'KotMutableMap[K, R]'],
+        initial_value_selector: Callable[[K, T], R],
+        operation: Callable[[K, R, T], R]
+    ) -> Union[Dict[K, R], 'KotMutableMap[K, R]']:
+        """Like fold(), but stores the results in the given destination map (a KotMutableMap or a dict).
+
+        A value already stored in the destination for a key is used as that group's accumulator.
+
+        Returns:
+            The destination map.
+        """
+        return self.aggregate_to(
+            destination,
+            lambda key, acc, element, first: operation(
+                key, initial_value_selector(key, element) if first else acc, element
+            )
+        )
+
+    def reduce_to(
+        self,
+        destination: Union[Dict[K, T], 'KotMutableMap[K, T]'],
+        operation: Callable[[K, T, T], T]
+    ) -> Union[Dict[K, T], 'KotMutableMap[K, T]']:
+        """Like reduce(), but stores the results in the given destination map (a KotMutableMap or a dict).
+
+        A value already stored in the destination for a key is used as that group's accumulator.
+
+        Returns:
+            The destination map.
+        """
+        return self.aggregate_to(
+            destination,
+            lambda key, acc, element, first: element if first else operation(key, acc, element)
+        )
+
+    def each_count_to(
+        self,
+        destination: Union[Dict[K, int], 'KotMutableMap[K, int]']
+    ) -> Union[Dict[K, int], 'KotMutableMap[K, int]']:
+        """Like each_count(), but adds the counts to the given destination map (a KotMutableMap or a dict).
+
+        Returns:
+            The destination map.
+        """
+        return self.aggregate_to(
+            destination,
+            lambda key, acc, element, first: 1 if first else acc + 1
+        )
+
+    def source_list(self) -> 'KotList[T]':
+        """Returns a KotList of the source elements in their original order."""
+        from kotcollections.kot_list import KotList
+        return KotList(list(self._source))
+
+    def key_of(self, element: T) -> K:
+        """Returns the key the key selector produces for the given element."""
+        return self._key_selector(element)
+
     def __repr__(self) -> str:
         return f"KotGrouping(source_size={len(self._source)})"
@@ -1,9 +1,15 @@
 from __future__ import annotations
 
 import bisect
+import io
+import math
+import numbers
 import random as _random
+from collections import Counter
 from collections.abc import Iterable
+from decimal import Decimal
 from functools import reduce, cmp_to_key
+from itertools import accumulate
 from typing import TypeVar, Generic, Callable, Optional, List, Tuple, Iterator, Any, Dict, Union, TYPE_CHECKING, Set, Type
 
 from kotcollections.type_checker import TypeChecker
@@ -12,16 +18,97 @@ T = TypeVar('T')
 R = TypeVar('R')
 K = TypeVar('K')
 V = TypeVar('V')
+W = TypeVar('W')
 
 if TYPE_CHECKING:
     from kotcollections.kot_map import KotMap
     from kotcollections.kot_mutable_list import KotMutableList
+    from kotcollections.kot_mutable_map import KotMutableMap
     from kotcollections.kot_set import KotSet
     from kotcollections.kot_mutable_set import KotMutableSet
 
 
+def _checked_comparator(comparator: Callable[[T, T], int]) -> Callable[[T], Any]:
+    """Convert a comparator into a sort key that reports comparator failures clearly.
+
+    Exceptions raised by the comparator propagate unchanged, with a note that they were raised
+    during a sort where the interpreter supports exception notes. A comparator returning anything
+    other than a real number raises TypeError.
+    """
+
+    def compare(a: T, b: T) -> int:
+        try:
+            result = comparator(a, b)
+        except Exception as e:
+            if hasattr(e, "add_note"):
+                e.add_note("comparator raised during sort")
+            raise
+        if not isinstance(result, numbers.Real):
+            raise TypeError(f"Comparator must return a number, got '{type(result).__name__}'")
+        return result
+
+    return cmp_to_key(compare)
+
+
+def _join_to(
+    elements: Iterable[T], destination: W, separator: str, prefix: str, postfix: str,
+    limit: int, truncated: str, transform: Optional[Callable[[T], str]]
+) -> W:
+    """Write the joined elements to destination piece by piece, as done by join_to()."""
+    if transform is None:
+        transform = str
+    destination.write(prefix)
+    for count, element in enumerate(elements):
+        if 0 <= limit <= count:
+            destination.write(truncated)
+            break
+        if count > 0:
+            destination.write(separator)
+        destination.write(transform(element))
+    destination.write(postfix)
+    return destination
+
+
+def _concat_sources(collections: Iterable[Iterable[T]]) -> Tuple[List[T], Optional[type], bool]:
+    """Concatenate the elements of several collections in one pass.
+
+    Returns the elements, the element type shared by every Kot collection source (None if they
+    disagree, or if a plain iterable contributed elements), and whether all those types were declared.
+    """
+    from kotcollections.kot_set import KotSet
+
+    elements: List[T] = []
+    types = set()
+    declared = True
+    untyped = False
+    for source in collections:
+        start = len(elements)
+        elements.extend(source)
+        if isinstance(source, (KotList, KotSet)) and source._element_type is not None:
+            types.add(source._element_type)
+            declared = declared and source.is_type_declared()
+        elif len(elements) > start:
+            untyped = True
+    if untyped or len(types) != 1:
+        return elements, None, False
+    return elements, types.pop(), declared
+
+
+def _variance(values: List[Any], sample: bool, collection_name: str) -> float:
+    """Compute the population or sample variance of values with a two-pass algorithm."""
+    n = len(values)
+    if n == 0:
+        raise ValueError(f"{collection_name} is empty.")
+    if sample and n < 2:
+        raise ValueError("Sample variance requires at least two elements.")
+    mean = sum(values) / n
+    return sum((value - mean) ** 2 for value in values) / (n - 1 if sample else n)
+
 
 class KotList(Generic[T]):
+    # True when the element type was declared via of_type() or KotList[T], not inferred from an element
+    _type_declared: bool = False
+
     def __init__(self, elements: Optional[Iterable[T]] = None):
         self._element_type: Optional[type] = None
         if elements is None:
@@ -56,6 +143,7 @@ class KotList(Generic[T]):
                     self._element_type = element_type
                 else:
                     self._element_type = None
+                self._type_declared = self._element_type is not None
                 self._elements = []
                 # Now process elements with the correct type set
                 if elements is not None:
@@ -105,10 +193,52 @@ class KotList(Generic[T]):
             >>> # Equivalent to __class_getitem__ syntax
             >>> animals = KotList[Animal]([Dog("Buddy"), Cat("Whiskers")])
         """
+        TypeChecker.validate_declared_type(element_type, "KotList")
         # Use __class_getitem__ to create the same dynamic subclass
         typed_class = cls[element_type]
         return typed_class(elements)
 
+    @classmethod
+    def empty_of_type(cls, element_type: Type[T]) -> 'KotList[T]':
+        """Create an empty KotList whose element type is fixed up front.
+
+        Unlike an empty ``KotList()``, which infers its type from the first element,
+        the returned list type-checks every element against ``element_type``.
+
+        Examples:
+            >>> animals = KotMutableList.empty_of_type(Animal)
+            >>> animals.add(Dog("Buddy"))  # OK
+            >>> animals.add("Buddy")  # TypeError
+        """
+        return cls.of_type(element_type)
+
+    @classmethod
+    def or_empty(cls, value: Optional[Iterable[T]]) -> 'KotList[T]':
+        """Returns an empty KotList if value is None, like Kotlin's orEmpty().
+
+        A KotList is returned as is; any other value is passed to the constructor.
+
+        Examples:
+            >>> KotList.or_empty(None)  # Returns an empty list
+        """
+        if value is None:
+            return cls()
+        if isinstance(value, cls):
+            return value
+        return cls(value)
+
+    @classmethod
+    def concat(cls, collections: Iterable[Iterable[T]]) -> 'KotList[T]':
+        """Concatenate many collections or iterables into one list in a single pass, preserving order.
+
+        The element type is kept when every Kot collection source has the same element type.
+
+        Examples:
+            >>> KotList.concat([KotList([1, 2]), [3], (4, 5)])  # Returns KotList([1, 2, 3, 4, 5])
+        """
+        elements, element_type, declared = _concat_sources(collections)
+        return cls._of_element_type(element_type, elements, declared)
+
     def _check_type(self, element: Any) -> None:
         """Check if the element has the correct type for this list.
 
@@ -125,12 +255,68 @@ class KotList(Generic[T]):
         # Validate the element type
         TypeChecker.validate_element(element, self._element_type, f"KotList")
 
+    @classmethod
+    def _of_element_type(cls, element_type: Optional[type], elements: Iterable[T], type_declared: bool) -> 'KotList[T]':
+        """Create a KotList of the given elements with element_type, recording whether that type was declared.
+
+        An element_type of None creates an untyped KotList that infers its type from the elements.
+        """
+        if element_type is None:
+            return cls(elements)
+        result = cls.of_type(element_type, elements)
+        result._type_declared = type_declared
+        return result
+
+    @classmethod
+    def _of_validated(
+        cls, elements: List[T], element_type: Optional[type], type_declared: bool
+    ) -> 'KotList[T]':
+        """Create a KotList backed by an already-validated list, without copying or re-checking it.
+
+        The caller guarantees that every element matches element_type and that the list is never
+        mutated afterwards.
+        """
+        result = cls.__new__(cls)
+        result._element_type = element_type
+        result._type_declared = type_declared
+        result._elements = elements
+        return result
+
+    def _with_element_type(self, elements: Iterable[T]) -> 'KotList[T]':
+        """Create a KotList of the given elements carrying this list's element type, if one is set."""
+        return KotList._of_element_type(self._element_type, elements, self._type_declared)
+
+    def _declared_type_suffix(self) -> str:
+        """Return "[type]" for use in repr when the element type was declared, or "" otherwise."""
+        if self.is_type_declared():
+            return f"[{self._element_type.__name__}]"
+        return ""
+
     def __repr__(self) -> str:
-        return f"KotList({self._elements})"
+        return f"KotList{self._declared_type_suffix()}({self._elements})"
 
     def __str__(self) -> str:
         return str(self._elements)
 
+    def __format__(self, format_spec: str) -> str:
+        """Format the list, using a non-empty format spec as the join separator (e.g. f"{lst:, }")."""
+        if not format_spec:
+            return str(self)
+        return self.join_to_string(separator=format_spec)
+
+    def equals_native(self, other: Any) -> bool:
+        """Returns true if this list is structurally equal to other once all Kot collections are unwrapped.
+
+        Nested KotList, KotSet and KotMap values compare equal to native lists, sets and dicts with the same
+        contents, which makes assertions against plain Python literals straightforward. Unlike ==, the
+        comparison ignores the Kot wrapping entirely.
+
+        Examples:
+            >>> KotList([KotSet([1]), KotMap({"a": 2})]).equals_native([{1}, {"a": 2}])  # True
+        """
+        from kotcollections.native_equality import native_equals
+        return native_equals(self, other)
+
     def __eq__(self, other: object) -> bool:
         if not isinstance(other, KotList):
             return False
@@ -145,6 +331,9 @@ class KotList(Generic[T]):
     def __getitem__(self, index: int) -> T:
         return self.get(index)
 
+    def __contains__(self, element: object) -> bool:
+        return self.contains(element)
+
     def __len__(self) -> int:
         return self.size
 
@@ -160,6 +349,19 @@ class KotList(Generic[T]):
     def last_index(self) -> int:
         return self.size - 1 if self.size > 0 else -1
 
+    def index_range(self) -> Tuple[int, int]:
+        """Returns the inclusive bounds (0, last_index) of the valid indices; (0, -1) for an empty list."""
+        return 0, self.last_index
+
+    @property
+    def element_type(self) -> Optional[type]:
+        """Returns the element type of this list, or None if it has not been determined yet."""
+        return self._element_type
+
+    def is_type_declared(self) -> bool:
+        """Returns true if the element type was declared via of_type() or KotList[T] rather than inferred."""
+        return self._type_declared and self._element_type is not None
+
     def is_empty(self) -> bool:
         return self.size == 0
 
@@ -167,11 +369,13 @@ class KotList(Generic[T]):
         return self.size > 0
 
     def get(self, index: int) -> T:
+        index = TypeChecker.validate_index(index)
         if not 0 <= index < self.size:
             raise IndexError(f"Index {index} out of bounds for list of size {self.size}")
         return self._elements[index]
 
     def get_or_null(self, index: int) -> Optional[T]:
+        index = TypeChecker.validate_index(index)
         return self._elements[index] if 0 <= index < self.size else None
 
     def get_or_none(self, index: int) -> Optional[T]:
@@ -179,6 +383,7 @@ class KotList(Generic[T]):
         return self.get_or_null(index)
 
     def get_or_else(self, index: int, default_value: Callable[[int], T]) -> T:
+        index = TypeChecker.validate_index(index)
         return self._elements[index] if 0 <= index < self.size else default_value(index)
 
     def first(self) -> T:
@@ -237,6 +442,50 @@ class KotList(Generic[T]):
         """Alias for last_or_null_predicate() - more Pythonic naming."""
         return self.last_or_null_predicate(predicate)
 
+    def first_is_instance(self, klass: Type[R]) -> R:
+        """Returns the first element that is an instance of klass.
+
+        Raises:
+            ValueError: If no element is an instance of klass.
+        """
+        for element in self._elements:
+            if isinstance(element, klass):
+                return element
+        raise ValueError(f"No element of type '{klass.__name__}' found")
+
+    def first_is_instance_or_null(self, klass: Type[R]) -> Optional[R]:
+        """Returns the first element that is an instance of klass, or null if there is none."""
+        for element in self._elements:
+            if isinstance(element, klass):
+                return element
+        return None
+
+    def first_is_instance_or_none(self, klass: Type[R]) -> Optional[R]:
+        """Alias for first_is_instance_or_null() - more Pythonic naming."""
+        return self.first_is_instance_or_null(klass)
+
+    def last_is_instance(self, klass: Type[R]) -> R:
+        """Returns the last element that is an instance of klass.
+
+        Raises:
+            ValueError: If no element is an instance of klass.
+        """
+        for element in reversed(self._elements):
+            if isinstance(element, klass):
+                return element
+        raise ValueError(f"No element of type '{klass.__name__}' found")
+
+    def last_is_instance_or_null(self, klass: Type[R]) -> Optional[R]:
+        """Returns the last element that is an instance of klass, or null if there is none."""
+        for element in reversed(self._elements):
+            if isinstance(element, klass):
+                return element
+        return None
+
+    def last_is_instance_or_none(self, klass: Type[R]) -> Optional[R]:
+        """Alias for last_is_instance_or_null() - more Pythonic naming."""
+        return self.last_is_instance_or_null(klass)
+
     def element_at(self, index: int) -> T:
         return self.get(index)
 
@@ -251,8 +500,28 @@ class KotList(Generic[T]):
         return self.element_at_or_null(index)
 
     def contains(self, element: T) -> bool:
+        index = self._element_index()
+        if index is not None:
+            try:
+                return element in index
+            except TypeError:
+                pass
         return element in self._elements
 
+    def _element_index(self) -> Optional[Set[T]]:
+        """Lazily build a hash index of the elements for fast membership checks.
+
+        Returns None if any element is unhashable, in which case lookups fall back to a linear scan.
+        The index is cached since KotList is immutable.
+        """
+        if not hasattr(self, '_cached_element_index'):
+            try:
+                index: Optional[Set[T]] = set(self._elements)
+            except TypeError:
+                index = None
+            self._cached_element_index = index
+        return self._cached_element_index
+
     def contains_all(self, elements: Iterable[T]) -> bool:
         # Support KotSet and KotMap explicitly
         from kotcollections.kot_set import KotSet
@@ -266,6 +535,14 @@ class KotList(Generic[T]):
             elements_set = set(elements)
         return all(elem in self._elements for elem in elements_set)
 
+    def contains_any(self, elements: Iterable[T]) -> bool:
+        """Returns true if at least one of the given elements is in this list, stopping at the first hit."""
+        from kotcollections.kot_map import KotMap
+
+        if isinstance(elements, KotMap):
+            elements = elements.values
+        return any(self.contains(element) for element in elements)
+
     def index_of(self, element: T) -> int:
         try:
             return self._elements.index(element)
@@ -345,6 +622,29 @@ class KotList(Generic[T]):
     def map_indexed(self, transform: Callable[[int, T], R]) -> 'KotList[R]':
         return KotList([transform(i, element) for i, element in enumerate(self._elements)])
 
+    def map_indexed_with_accumulator(
+        self,
+        initial: V,
+        transform: Callable[[int, V, T], Tuple[V, R]]
+    ) -> 'KotList[R]':
+        """Maps each element while threading an accumulator through the transform.
+
+        transform(index, accumulator, element) returns (new_accumulator, mapped_value); the mapped
+        values are collected and the final accumulator is discarded.
+
+        Examples:
+            >>> # Running label numbering per kind
+            >>> KotList(["a", "b", "a"]).map_indexed_with_accumulator(
+            ...     {}, lambda i, seen, e: ({**seen, e: seen.get(e, 0) + 1}, f"{e}{seen.get(e, 0)}")
+            ... )  # Returns KotList(['a0', 'b0', 'a1'])
+        """
+        result = []
+        accumulator = initial
+        for i, element in enumerate(self._elements):
+            accumulator, mapped = transform(i, accumulator, element)
+            result.append(mapped)
+        return KotList(result)
+
     def map_not_null(self, transform: Callable[[T], Optional[R]]) -> 'KotList[R]':
         result = []
         for element in self._elements:
@@ -372,9 +672,48 @@ class KotList(Generic[T]):
                 result.append(element)
         return KotList(result)
 
+    def flatten_to_map(self, on_conflict: str = "last") -> 'KotMap[Any, Any]':
+        """Merges all map elements of this list into a single KotMap.
+
+        Args:
+            on_conflict: "last" to keep the value from the latest map on key collisions,
+                         or "first" to keep the value from the earliest map
+
+        Raises:
+            TypeError: If an element is not a KotMap or dict
+            ValueError: If on_conflict is neither "first" nor "last"
+
+        Examples:
+            >>> KotList([KotMap({"a": 1}), KotMap({"a": 2, "b": 3})]).flatten_to_map()
+            >>> # Returns KotMap({'a': 2, 'b': 3})
+        """
+        from kotcollections.kot_map import KotMap
+        if on_conflict not in ("first", "last"):
+            raise ValueError(f"on_conflict must be 'first' or 'last', got {on_conflict!r}")
+        result = {}
+        for element in self._elements:
+            if isinstance(element, KotMap):
+                items = element._elements.items()
+            elif isinstance(element, dict):
+                items = element.items()
+            else:
+                raise TypeError(f"Cannot flatten element of type '{type(element).__name__}' into a map")
+            for key, value in items:
+                if on_conflict == "last" or key not in result:
+                    result[key] = value
+        return KotMap(result)
+
     def associate_with(self, value_selector: Callable[[T], V]) -> 'KotMap[T, V]':
+        """Returns a Map where keys are elements from this list and values are produced by value_selector.
+
+        The selector is invoked once per distinct element, and the map's key type is this list's element type.
+        """
         from kotcollections.kot_map import KotMap
-        return KotMap({element: value_selector(element) for element in self._elements})
+        result = {}
+        for element in self._elements:
+            if element not in result:
+                result[element] = value_selector(element)
+        return KotMap._of_types(self._element_type, None, result, self._type_declared, False)
 
     def associate_by(self, key_selector: Callable[[T], K]) -> 'KotMap[K, T]':
         from kotcollections.kot_map import KotMap
@@ -447,6 +786,44 @@ class KotList(Generic[T]):
                 non_matching.append(element)
         return KotList(matching), KotList(non_matching)
 
+    def partition_indexed(self, predicate: Callable[[int, T], bool]) -> Tuple['KotList[T]', 'KotList[T]']:
+        """Splits this list into elements for which predicate(index, element) is true and those for which it is false.
+
+        Both lists keep the original order and this list's element type.
+
+        Examples:
+            >>> KotList(["a", "b", "c", "d"]).partition_indexed(lambda i, _: i % 2 == 0)
+            >>> # Returns (KotList(['a', 'c']), KotList(['b', 'd']))
+        """
+        matching = []
+        non_matching = []
+        for i, element in enumerate(self._elements):
+            if predicate(i, element):
+                matching.append(element)
+            else:
+                non_matching.append(element)
+        return self._with_element_type(matching), self._with_element_type(non_matching)
+
+    def partition_by_transform(self, transform: Callable[[T], Tuple[bool, R]]) -> Tuple['KotList[Any]', 'KotList[Any]']:
+        """Splits this list in one pass using a transform that returns an (is_right, value) pair.
+
+        Values whose is_right flag is false go to the first list, the others to the second list.
+
+        Examples:
+            >>> lst = KotList(["1", "x", "3"])
+            >>> lst.partition_by_transform(lambda s: (True, int(s)) if s.isdigit() else (False, s))
+            >>> # Returns (KotList(['x']), KotList([1, 3]))
+        """
+        left = []
+        right = []
+        for element in self._elements:
+            is_right, value = transform(element)
+            if is_right:
+                right.append(value)
+            else:
+                left.append(value)
+        return KotList(left), KotList(right)
+
     def any(self, predicate: Optional[Callable[[T], bool]] = None) -> bool:
         if predicate is None:
             return self.is_not_empty()
@@ -465,6 +842,54 @@ class KotList(Generic[T]):
             return self.size
         return sum(1 for element in self._elements if predicate(element))
 
+    def count_distinct(self) -> int:
+        """Returns the number of distinct elements, without building the distinct list."""
+        distinct = set()
+        for element in self._elements:
+            TypeChecker.validate_hashable(element, "count_distinct element")
+            distinct.add(element)
+        return len(distinct)
+
+    def count_distinct_by(self, selector: Callable[[T], K]) -> int:
+        """Returns the number of distinct keys returned by the given selector."""
+        return len({selector(element) for element in self._elements})
+
+    def count_by(self, key_selector: Optional[Callable[[T], K]] = None) -> 'KotMap[Any, int]':
+        """Returns a KotMap from each key (or each element if no selector is given) to the number of elements with it.
+
+        Examples:
+            >>> KotList(["apple", "avocado", "banana"]).count_by(lambda s: s[0])  # Returns KotMap({'a': 2, 'b': 1})
+        """
+        from kotcollections.kot_map import KotMap
+        counts: Dict[Any, int] = {}
+        for element in self._elements:
+            key = key_selector(element) if key_selector is not None else element
+            TypeChecker.validate_hashable(key, "count_by key")
+            counts[key] = counts.get(key, 0) + 1
+        if key_selector is None and self._element_type is not None:
+            return KotMap._of_types(self._element_type, int, counts, self._type_declared, False)
+        return KotMap(counts)
+
+    def most_common(self, n: Optional[int] = None) -> 'KotList[Tuple[T, int]]':
+        """Returns (element, count) pairs sorted by descending count, like collections.Counter.most_common.
+
+        Ties keep the order of first appearance. At most n pairs are returned when n is given.
+
+        Raises:
+            ValueError: If n is negative
+        """
+        if n is not None and n < 0:
+            raise ValueError("Requested element count is less than zero")
+        entries = sorted(self.count_by().to_dict().items(), key=lambda entry: -entry[1])
+        return KotList(entries if n is None else entries[:n])
+
+    def sum(self) -> Any:
+        """Returns the sum of all elements, or 0 for an empty list.
+
+        Elements are added with Python's own addition, so Decimal and Fraction sums stay exact.
+        """
+        return sum(self._elements)
+
     def sum_of(self, selector: Callable[[T], Union[int, float]]) -> Union[int, float]:
         return sum(selector(element) for element in self._elements)
 
@@ -520,29 +945,109 @@ class KotList(Generic[T]):
         """Alias for min_or_null() - more Pythonic naming."""
         return self.min_or_null()
 
-    def max_by_or_null(self, selector: Callable[[T], Any]) -> Optional[T]:
+    @staticmethod
+    def _extreme_key(
+        selector: Callable[[T], Any],
+        tie_breaker: Optional[Callable[[T], Any]]
+    ) -> Callable[[T], Any]:
+        """Build the key for max_by/min_by, ordering equal selector values by tie_breaker if given."""
+        if tie_breaker is None:
+            return selector
+        return lambda element: (selector(element), tie_breaker(element))
+
+    def max_by_or_null(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> Optional[T]:
         if self.is_empty():
             return None
-        return max(self._elements, key=selector)
+        return max(self._elements, key=self._extreme_key(selector, tie_breaker))
 
-    def max_by_or_none(self, selector: Callable[[T], Any]) -> Optional[T]:
+    def max_by_or_none(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> Optional[T]:
         """Alias for max_by_or_null() - more Pythonic naming."""
-        return self.max_by_or_null(selector)
+        return self.max_by_or_null(selector, tie_breaker)
 
-    def min_by_or_null(self, selector: Callable[[T], Any]) -> Optional[T]:
+    def min_by_or_null(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> Optional[T]:
         if self.is_empty():
             return None
-        return min(self._elements, key=selector)
+        return min(self._elements, key=self._extreme_key(selector, tie_breaker))
 
-    def min_by_or_none(self, selector: Callable[[T], Any]) -> Optional[T]:
+    def min_by_or_none(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> Optional[T]:
         """Alias for min_by_or_null() - more Pythonic naming."""
-        return self.min_by_or_null(selector)
+        return self.min_by_or_null(selector, tie_breaker)
 
     def average(self) -> float:
         if self.is_empty():
             return float('nan')  # Kotlin-compatible: returns NaN for empty collections
         return sum(self._elements) / self.size
 
+    def average_or_null(self) -> Optional[float]:
+        """Returns the average of all elements, or null if the list is empty."""
+        if self.is_empty():
+            return None
+        return self.average()
+
+    def average_or_none(self) -> Optional[float]:
+        """Alias for average_or_null() - more Pythonic naming."""
+        return self.average_or_null()
+
+    def median(self) -> Any:
+        """Returns the middle element of the sorted list, or the mean of the two middle elements for even sizes.
+
+        Raises:
+            ValueError: If the list is empty
+        """
+        if self.is_empty():
+            raise ValueError("List is empty.")
+        ordered = sorted(self._elements)
+        middle = len(ordered) // 2
+        if len(ordered) % 2 == 1:
+            return ordered[middle]
+        return (ordered[middle - 1] + ordered[middle]) / 2
+
+    def percentile(self, p: float) -> float:
+        """Returns the p-th percentile (0 to 100) of the elements, using linear interpolation between ranks.
+
+        Raises:
+            ValueError: If the list is empty or p is outside 0..100
+
+        Examples:
+            >>> KotList([1, 2, 3, 4]).percentile(50)  # Returns 2.5
+            >>> KotList([1, 2, 3, 4]).percentile(25)  # Returns 1.75
+            >>> KotList([Decimal("1"), Decimal("2")]).percentile(50)  # Returns Decimal('1.5')
+        """
+        if self.is_empty():
+            raise ValueError("List is empty.")
+        if not 0 <= p <= 100:
+            raise ValueError(f"Percentile must be between 0 and 100, got {p}")
+        ordered = sorted(self._elements)
+        rank = (len(ordered) - 1) * p / 100
+        lower = int(rank)
+        upper = min(lower + 1, len(ordered) - 1)
+        delta = ordered[upper] - ordered[lower]
+        fraction = rank - lower
+        if isinstance(delta, Decimal):
+            # Decimal does not mix with float, so interpolate in Decimal arithmetic
+            fraction = Decimal(str(fraction))
+        return ordered[lower] + delta * fraction
+
+    def variance(self, sample: bool = False) -> float:
+        """Returns the population variance of the elements, or the sample variance if sample is True.
+
+        Raises:
+            ValueError: If the list is empty, or has fewer than two elements for the sample variance
+        """
+        return _variance(self._elements, sample, "List")
+
+    def standard_deviation(self, sample: bool = False) -> float:
+        """Returns the population standard deviation, or the sample standard deviation if sample is True."""
+        return math.sqrt(self.variance(sample))
+
     def sorted(self, key: Optional[Callable[[T], Any]] = None, reverse: bool = False) -> 'KotList[T]':
         return KotList(sorted(self._elements, key=key, reverse=reverse))
 
@@ -556,12 +1061,27 @@ class KotList(Generic[T]):
         return KotList(sorted(self._elements, key=selector, reverse=True))
 
     def sorted_with(self, comparator: Callable[[T, T], int]) -> 'KotList[T]':
-        """Returns a list of all elements sorted according to the specified comparator."""
-        return KotList(sorted(self._elements, key=cmp_to_key(comparator)))
+        """Returns a list of all elements sorted according to the specified comparator.
+
+        Raises:
+            TypeError: If the comparator returns something other than a number.
+        """
+        return KotList(sorted(self._elements, key=_checked_comparator(comparator)))
 
     def reversed(self) -> 'KotList[T]':
         return KotList(reversed(self._elements))
 
+    def reverse_iterator(self) -> Iterator[T]:
+        """Returns an iterator over the elements from last to first without copying the list.
+
+        Unlike reversed(), nothing is materialized, so stopping early is cheap.
+
+        Examples:
+            >>> for x in KotList([1, 2, 3]).reverse_iterator():
+            ...     print(x)  # Prints 3, 2, 1
+        """
+        return reversed(self._elements)
+
     def shuffled(self, random_instance: Optional[_random.Random] = None) -> 'KotList[T]':
         elements_copy = self._elements.copy()
         if random_instance:
@@ -570,11 +1090,31 @@ class KotList(Generic[T]):
             _random.shuffle(elements_copy)
         return KotList(elements_copy)
 
+    def rotated(self, n: int) -> 'KotList[T]':
+        """Returns a list with the elements rotated right by n positions, or left if n is negative.
+
+        n is taken modulo the list size.
+
+        Examples:
+            >>> KotList([1, 2, 3, 4]).rotated(1)  # Returns KotList([4, 1, 2, 3])
+            >>> KotList([1, 2, 3, 4]).rotated(-1)  # Returns KotList([2, 3, 4, 1])
+        """
+        return self._with_element_type(self._rotated_elements(n))
+
+    def _rotated_elements(self, n: int) -> List[T]:
+        """Return a copy of the elements rotated right by n positions."""
+        n = TypeChecker.validate_integer(n, "Rotation distance")
+        if not self._elements:
+            return []
+        shift = n % len(self._elements)
+        return self._elements[-shift:] + self._elements[:-shift] if shift else list(self._elements)
+
     def group_by(self, key_selector: Callable[[T], K]) -> 'KotMap[K, KotList[T]]':
         from kotcollections.kot_map import KotMap
         result: Dict[K, List[T]] = {}
         for element in self._elements:
             key = key_selector(element)
+            TypeChecker.validate_hashable(key, "group_by key")
             if key not in result:
                 result[key] = []
             result[key].append(element)
@@ -588,11 +1128,46 @@ class KotList(Generic[T]):
         result: Dict[K, List[V]] = {}
         for element in self._elements:
             key = key_selector(element)
+            TypeChecker.validate_hashable(key, "group_by_with_value key")
             if key not in result:
                 result[key] = []
             result[key].append(value_transform(element))
         return KotMap({k: KotList(v) for k, v in result.items()})
 
+    def aggregate_by(
+        self,
+        key_selector: Callable[[T], K],
+        aggregator: Callable[['KotList[T]'], R]
+    ) -> 'KotMap[K, R]':
+        """Groups elements by key and returns a map from each key to aggregator applied to that group.
+
+        Examples:
+            >>> employees.aggregate_by(lambda e: e.dept, lambda g: g.sum_of(lambda e: e.salary))
+        """
+        from kotcollections.kot_map import KotMap
+        groups: Dict[K, List[T]] = {}
+        for element in self._elements:
+            key = key_selector(element)
+            TypeChecker.validate_hashable(key, "aggregate_by key")
+            groups.setdefault(key, []).append(element)
+        return KotMap({k: aggregator(self._with_element_type(v)) for k, v in groups.items()})
+
+    def group_by_mutable(self, key_selector: Callable[[T], K]) -> 'KotMutableMap[K, KotMutableList[T]]':
+        """Like group_by(), but returns a KotMutableMap of KotMutableList groups that can be modified afterwards.
+
+        Keys keep their first-seen order and each group keeps the order of its elements.
+        """
+        from kotcollections.kot_mutable_list import KotMutableList
+        from kotcollections.kot_mutable_map import KotMutableMap
+        groups: Dict[K, KotMutableList[T]] = {}
+        for element in self._elements:
+            key = key_selector(element)
+            TypeChecker.validate_hashable(key, "group_by_mutable key")
+            if key not in groups:
+                groups[key] = self._with_element_type([]).to_kot_mutable_list()
+            groups[key].add(element)
+        return KotMutableMap(groups)
+
     def grouping_by(self, key_selector: Callable[[T], K]) -> 'KotGrouping[T, K]':
         """Creates a Grouping source from this list to be used later with one of group-and-fold operations
         using the specified keySelector function to extract a key from each element.
@@ -624,15 +1199,47 @@ class KotList(Generic[T]):
             raise ValueError("Size must be positive")
         chunks = []
         for i in range(0, len(self._elements), size):
-            chunks.append(KotList(self._elements[i:i + size]))
+            chunks.append(self._with_element_type(self._elements[i:i + size]))
+        return KotList(chunks)
+
+    def chunked_by(self, predicate: Callable[[T, T], bool]) -> 'KotList[KotList[T]]':
+        """Splits this list into chunks, starting a new chunk wherever predicate(previous, current) is true.
+
+        Examples:
+            >>> KotList([1, 1, 2, 3, 3]).chunked_by(lambda a, b: a != b)
+            >>> # Returns KotList([KotList([1, 1]), KotList([2]), KotList([3, 3])])
+        """
+        if not self._elements:
+            return KotList()
+        chunks = []
+        current = [self._elements[0]]
+        for previous, element in zip(self._elements, self._elements[1:]):
+            if predicate(previous, element):
+                chunks.append(self._with_element_type(current))
+                current = []
+            current.append(element)
+        chunks.append(self._with_element_type(current))
         return KotList(chunks)
 
+    def chunked_with_remainder(self, size: int) -> Tuple['KotList[KotList[T]]', 'KotList[T]']:
+        """Splits this list into chunks of exactly the given size and the leftover elements.
+
+        Returns:
+            A tuple (full_chunks, remainder) where remainder holds the trailing elements
+            that did not fill a whole chunk (possibly empty).
+        """
+        if size <= 0:
+            raise ValueError("Size must be positive")
+        full_length = len(self._elements) - len(self._elements) % size
+        chunks = [self._with_element_type(self._elements[i:i + size]) for i in range(0, full_length, size)]
+        return KotList(chunks), self._with_element_type(self._elements[full_length:])
+
     def chunked_transform(self, size: int, transform: Callable[['KotList[T]'], R]) -> 'KotList[R]':
         if size <= 0:
             raise ValueError("Size must be positive")
         result = []
         for i in range(0, len(self._elements), size):
-            chunk = KotList(self._elements[i:i + size])
+            chunk = self._with_element_type(self._elements[i:i + size])
             result.append(transform(chunk))
         return KotList(result)
 
@@ -643,28 +1250,66 @@ class KotList(Generic[T]):
         for i in range(0, len(self._elements), step):
             window = self._elements[i:i + size]
             if len(window) == size or (partial_windows and window):
-                windows.append(KotList(window))
+                windows.append(self._with_element_type(window))
             elif not partial_windows and len(window) < size:
                 break
         return KotList(windows)
 
-    def distinct(self) -> 'KotList[T]':
-        seen = set()
-        result = []
-        for element in self._elements:
-            if element not in seen:
-                seen.add(element)
-                result.append(element)
-        return KotList(result)
+    def moving_aggregate(
+        self,
+        size: int,
+        operation: Callable[['KotList[T]'], R],
+        step: int = 1
+    ) -> 'KotList[R]':
+        """Applies operation to each full window of the given size and returns the per-window results.
+
+        Equivalent to windowed(size, step).map(operation) without building the intermediate list of windows.
+
+        Examples:
+            >>> KotList([1, 2, 3, 4]).moving_aggregate(2, lambda w: w.sum())  # Returns KotList([3, 5, 7])
+        """
+        if size <= 0 or step <= 0:
+            raise ValueError("Size and step must be positive")
+        results = []
+        for i in range(0, len(self._elements) - size + 1, step):
+            results.append(operation(self._with_element_type(self._elements[i:i + size])))
+        return KotList(results)
+
+    def windowed_average(self, size: int, step: int = 1, partial_windows: bool = False) -> 'KotList[float]':
+        """Returns the mean of each window produced by windowed(size, step, partial_windows), as floats.
 
-    def distinct_by(self, selector: Callable[[T], K]) -> 'KotList[T]':
+        Examples:
+            >>> KotList([1, 2, 3, 4]).windowed_average(2)  # Returns KotList([1.5, 2.5, 3.5])
+        """
+        averages = []
+        for window in self.windowed(size, step, partial_windows):
+            if window.is_not_empty():
+                averages.append(float(sum(window._elements) / window.size))
+        return KotList.of_type(float, averages)
+
+    def distinct(self, keep_last: bool = False) -> 'KotList[T]':
+        """Returns a list containing only distinct elements.
+
+        By default the first occurrence of each element is kept. With keep_last=True the last
+        occurrence is kept instead, and the result is ordered by the positions of those last occurrences.
+        """
+        return self.distinct_by(lambda element: element, keep_last)
+
+    def distinct_by(self, selector: Callable[[T], K], keep_last: bool = False) -> 'KotList[T]':
+        """Returns a list containing only elements having distinct keys returned by the given selector.
+
+        By default the first element for each key is kept. With keep_last=True the last element
+        for each key is kept instead, and the result is ordered by the positions of those elements.
+        """
         seen = set()
         result = []
-        for element in self._elements:
+        for element in (reversed(self._elements) if keep_last else self._elements):
             key = selector(element)
             if key not in seen:
                 seen.add(key)
                 result.append(element)
+        if keep_last:
+            result.reverse()
         return KotList(result)
 
     def intersect(self, other: Iterable[T]) -> 'KotSet[T]':
@@ -711,6 +1356,35 @@ class KotList(Generic[T]):
             remove = {other}
         return KotSet(base - remove)
 
+    def diff(self, other: Iterable[T]) -> 'KotMap[str, KotList[T]]':
+        """Compares this list with other using equality and returns the differences.
+
+        Returns:
+            A KotMap with the keys "added" (elements of other not in this list), "removed"
+            (elements of this list not in other) and "common" (elements of this list also in other).
+            Each value is a KotList preserving the original order, duplicates included.
+
+        Examples:
+            >>> KotList([1, 2, 3]).diff([2, 3, 4])
+            >>> # Returns KotMap({'added': KotList([4]), 'removed': KotList([1]), 'common': KotList([2, 3])})
+        """
+        from kotcollections.kot_map import KotMap
+
+        others = list(other.values) if isinstance(other, KotMap) else list(other)
+        return KotMap({
+            "added": KotList([element for element in others if element not in self._elements]),
+            "removed": self._with_element_type([element for element in self._elements if element not in others]),
+            "common": self._with_element_type([element for element in self._elements if element in others]),
+        })
+
+    def prepend(self, element: T) -> 'KotList[T]':
+        """Returns a new list with the given element added at the front, preserving the element type."""
+        return self._with_element_type([element] + self._elements)
+
+    def prepend_all(self, elements: Iterable[T]) -> 'KotList[T]':
+        """Returns a new list with the given elements added at the front, in order, preserving the element type."""
+        return self._with_element_type(list(elements) + self._elements)
+
     def plus(self, element: Union[T, Iterable[T]]) -> 'KotList[T]':
         # Support KotSet and KotMap explicitly
         from kotcollections.kot_set import KotSet
@@ -752,8 +1426,44 @@ class KotList(Generic[T]):
             return KotList(result)
 
     def sub_list(self, from_index: int, to_index: int) -> 'KotList[T]':
+        from_index = TypeChecker.validate_index(from_index)
+        to_index = TypeChecker.validate_index(to_index)
         return KotList(self._elements[from_index:to_index])
 
+    def get_range(self, from_index: int, to_index: int, step: int = 1) -> 'KotList[T]':
+        """Returns a list of the elements between from_index (inclusive) and to_index (exclusive), taking every step-th element.
+
+        A negative step walks the range backwards, in which case from_index must not be less than to_index.
+        Use -1 as to_index to include the first element, e.g. get_range(last_index, -1, -1) reverses the list.
+
+        Raises:
+            ValueError: If step is zero.
+            IndexError: If the bounds are out of range for the direction given by step.
+
+        Examples:
+            >>> lst = KotList([0, 1, 2, 3, 4, 5])
+            >>> lst.get_range(1, 5, 2).to_list()
+            [1, 3]
+            >>> lst.get_range(5, 0, -2).to_list()
+            [5, 3, 1]
+        """
+        from_index = TypeChecker.validate_index(from_index)
+        to_index = TypeChecker.validate_index(to_index)
+        step = TypeChecker.validate_index(step)
+        if step == 0:
+            raise ValueError("Step must not be zero")
+        if step > 0:
+            if from_index < 0 or to_index > self.size:
+                raise IndexError(f"fromIndex {from_index} or toIndex {to_index} out of bounds for list of size {self.size}")
+            if from_index > to_index:
+                raise IndexError(f"fromIndex {from_index} > toIndex {to_index}")
+        else:
+            if to_index < -1 or from_index >= self.size:
+                raise IndexError(f"fromIndex {from_index} or toIndex {to_index} out of bounds for list of size {self.size}")
+            if from_index < to_index:
+                raise IndexError(f"fromIndex {from_index} < toIndex {to_index} with negative step")
+        return KotList([self._elements[i] for i in range(from_index, to_index, step)])
+
     def zip(self, other: Iterable[R]) -> 'KotList[Tuple[T, R]]':
         # Support KotSet and KotMap explicitly
         from kotcollections.kot_set import KotSet
@@ -780,6 +1490,52 @@ class KotList(Generic[T]):
         
         return KotList([transform(a, b) for a, b in zip(self._elements, iter_other)])
 
+    def zip_longest(self, other: Iterable[R], fill_value: Any = None) -> 'KotList[Tuple[Any, Any]]':
+        """Returns a list of pairs built from this list and other, padding the shorter side with fill_value.
+
+        The result has max(len(self), len(other)) pairs.
+
+        Examples:
+            >>> KotList([1, 2, 3]).zip_longest(["a"])  # Returns KotList([(1, 'a'), (2, None), (3, None)])
+        """
+        from itertools import zip_longest
+        from kotcollections.kot_map import KotMap
+
+        iter_other = other.values if isinstance(other, KotMap) else other
+        return KotList(list(zip_longest(self._elements, iter_other, fillvalue=fill_value)))
+
+    def cross(self, other: Iterable[R], transform: Optional[Callable[[T, R], V]] = None) -> 'KotList[Any]':
+        """Returns the cartesian product of this list and other.
+
+        Each element a of this list is paired with every element b of other, producing (a, b)
+        or transform(a, b) if a transform is given.
+
+        Examples:
+            >>> KotList([1, 2]).cross(["a", "b"])  # Returns KotList([(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')])
+            >>> KotList([1, 2]).cross([10, 20], lambda a, b: a * b)  # Returns KotList([10, 20, 20, 40])
+        """
+        others = list(other)
+        if transform is None:
+            return KotList([(a, b) for a in self._elements for b in others])
+        return KotList([transform(a, b) for a in self._elements for b in others])
+
+    def interleave(self, other: Iterable[Any]) -> 'KotList[Any]':
+        """Returns a list alternating elements of this list and other, followed by the rest of the longer one.
+
+        The two sources may hold different types, so the result is a KotList[object].
+
+        Examples:
+            >>> KotList([1, 2, 3]).interleave(["a"])  # Returns KotList[object]([1, 'a', 2, 3])
+        """
+        others = list(other)
+        merged = []
+        for i in range(max(len(self._elements), len(others))):
+            if i < len(self._elements):
+                merged.append(self._elements[i])
+            if i < len(others):
+                merged.append(others[i])
+        return KotList.of_type(object, merged)
+
     def unzip(self) -> Tuple['KotList[Any]', 'KotList[Any]']:
         if self.is_empty():
             return KotList(), KotList()
@@ -828,62 +1584,130 @@ class KotList(Generic[T]):
     def to_set(self) -> Set[T]:
         return set(self._elements.copy())
 
+    def _to_native_list(self, converter: Callable[[Any], R]) -> List[R]:
+        """Convert every element with converter, reporting the index of the first failure as a TypeError."""
+        result = []
+        for i, element in enumerate(self._elements):
+            try:
+                result.append(converter(element))
+            except (TypeError, ValueError) as e:
+                raise TypeError(
+                    f"Element at index {i} ({element!r}) cannot be converted to {converter.__name__}"
+                ) from e
+        return result
+
+    def to_int_list(self) -> List[int]:
+        """Returns a Python list of the elements converted with int().
+
+        Fractional numbers are rejected rather than truncated, so 2.0 converts to 2 but 1.9 raises.
+
+        Raises:
+            TypeError: If an element cannot be converted; the message names its index
+            ValueError: If a numeric element is not a finite integral value; the message names its index
+        """
+        for i, element in enumerate(self._elements):
+            if isinstance(element, (numbers.Real, Decimal)) and not isinstance(element, numbers.Integral):
+                if not math.isfinite(element) or element != int(element):
+                    raise ValueError(f"Element at index {i} ({element!r}) is not an integral value")
+        return self._to_native_list(int)
+
+    def to_float_list(self) -> List[float]:
+        """Returns a Python list of the elements converted with float().
+
+        Raises:
+            TypeError: If an element cannot be converted; the message names its index
+        """
+        return self._to_native_list(float)
+
+    def to_str_list(self) -> List[str]:
+        """Returns a Python list of the elements converted with str()."""
+        return self._to_native_list(str)
+
+    def to_counter(self, key_selector: Optional[Callable[[T], K]] = None) -> Counter:
+        """Returns a collections.Counter of the elements, or of the keys produced by key_selector.
+
+        Raises:
+            TypeError: If an element or key is not hashable
+        """
+        counter: Counter = Counter()
+        for element in self._elements:
+            key = key_selector(element) if key_selector is not None else element
+            TypeChecker.validate_hashable(key, "to_counter key")
+            counter[key] += 1
+        return counter
+
     def to_kot_list(self) -> 'KotList[T]':
-        # Preserve type information when converting
-        if self._element_type is not None:
-            return KotList.of_type(self._element_type, self._elements.copy())
-        else:
-            return KotList(self._elements.copy())
+        """Returns a KotList with the same elements and element type, sharing this list's storage.
+
+        KotList never mutates its backing list, so sharing it makes the copy O(1).
+        KotMutableList overrides this method to copy its elements instead.
+        """
+        return type(self)._of_validated(self._elements, self._element_type, self._type_declared)
 
     def to_kot_mutable_list(self) -> 'KotMutableList[T]':
         from kotcollections.kot_mutable_list import KotMutableList
         # Preserve type information when converting
-        if self._element_type is not None:
-            mutable_list = KotMutableList.of_type(self._element_type, self._elements.copy())
-        else:
-            mutable_list = KotMutableList(self._elements.copy())
-        return mutable_list
+        return KotMutableList._of_element_type(self._element_type, self._elements.copy(), self._type_declared)
 
     def to_kot_set(self) -> 'KotSet[T]':
         from kotcollections.kot_set import KotSet
         # Preserve type information when converting
-        if self._element_type is not None:
-            return KotSet.of_type(self._element_type, self._elements.copy())
-        else:
-            return KotSet(self._elements.copy())
+        return KotSet._of_element_type(self._element_type, self._elements.copy(), self._type_declared)
 
     def to_kot_mutable_set(self) -> 'KotMutableSet[T]':
         from kotcollections.kot_mutable_set import KotMutableSet
         # Preserve type information when converting
-        if self._element_type is not None:
-            return KotMutableSet.of_type(self._element_type, self._elements.copy())
-        else:
-            return KotMutableSet(self._elements.copy())
+        return KotMutableSet._of_element_type(self._element_type, self._elements.copy(), self._type_declared)
 
-    def join_to_string(
-        self, separator: str = ", ", prefix: str = "", postfix: str = "",
-        limit: int = -1, truncated: str = "...",
-        transform: Optional[Callable[[T], str]] = None
-    ) -> str:
-        if transform is None:
-            transform = str
+    def _as_pairs(self) -> List[Tuple[Any, Any]]:
+        """Interpret each element as a (key, value) pair, raising ValueError for anything else."""
+        pairs = []
+        for i, element in enumerate(self._elements):
+            if not isinstance(element, (tuple, list)) or len(element) != 2:
+                raise ValueError(f"Element at index {i} is not a (key, value) pair: {element!r}")
+            pairs.append((element[0], element[1]))
+        return pairs
 
-        result = prefix
-        count = 0
+    def to_kot_map(self) -> 'KotMap[Any, Any]':
+        """Returns a KotMap built from this list of (key, value) pairs; later pairs win on duplicate keys.
 
-        for i, element in enumerate(self._elements):
-            if limit >= 0 and count >= limit:
-                result += truncated
-                break
+        Raises:
+            ValueError: If an element is not a 2-element tuple or list.
+        """
+        from kotcollections.kot_map import KotMap
+        return KotMap(self._as_pairs())
 
-            if i > 0:
-                result += separator
+    def to_kot_map_of_types(self, key_type: Type[K], value_type: Type[V]) -> 'KotMap[K, V]':
+        """Returns a KotMap with the given key and value types built from this list of (key, value) pairs.
 
-            result += transform(element)
-            count += 1
+        Raises:
+            ValueError: If an element is not a 2-element tuple or list.
+            TypeError: If a key or value is not an instance of its declared type.
+        """
+        from kotcollections.kot_map import KotMap
+        return KotMap.of_type(key_type, value_type, self._as_pairs())
 
-        result += postfix
-        return result
+    def join_to(
+        self, destination: W, separator: str = ", ", prefix: str = "", postfix: str = "",
+        limit: int = -1, truncated: str = "...",
+        transform: Optional[Callable[[T], str]] = None
+    ) -> W:
+        """Appends the string from all the elements separated using separator to the given destination.
+
+        The destination is any object with a write(str) method, such as io.StringIO or an open text file,
+        so large joins can be streamed without building the whole string in memory.
+
+        Returns:
+            The destination.
+        """
+        return _join_to(self._elements, destination, separator, prefix, postfix, limit, truncated, transform)
+
+    def join_to_string(
+        self, separator: str = ", ", prefix: str = "", postfix: str = "",
+        limit: int = -1, truncated: str = "...",
+        transform: Optional[Callable[[T], str]] = None
+    ) -> str:
+        return self.join_to(io.StringIO(), separator, prefix, postfix, limit, truncated, transform).getvalue()
 
     # Element retrieval methods
     def component1(self) -> T:
@@ -977,6 +1801,70 @@ class KotList(Generic[T]):
         """Alias for random_or_null() - more Pythonic naming."""
         return self.random_or_null(random_instance)
 
+    def random_take(self, n: int, random_instance: Optional[_random.Random] = None) -> 'KotList[T]':
+        """Returns a list of n distinct randomly chosen elements from this list (sampling without replacement).
+
+        Raises:
+            ValueError: If n is negative or greater than the size of this list.
+        """
+        if n < 0:
+            raise ValueError("Requested element count is less than zero")
+        if n > self.size:
+            raise ValueError(f"Requested element count {n} is greater than list size {self.size}")
+        if random_instance:
+            return KotList(random_instance.sample(self._elements, n))
+        return KotList(_random.sample(self._elements, n))
+
+    def random_take_or_null(self, n: int, random_instance: Optional[_random.Random] = None) -> Optional['KotList[T]']:
+        """Returns a list of n distinct randomly chosen elements from this list, or null if n is out of range."""
+        if not 0 <= n <= self.size:
+            return None
+        return self.random_take(n, random_instance)
+
+    def random_take_or_none(self, n: int, random_instance: Optional[_random.Random] = None) -> Optional['KotList[T]']:
+        """Alias for random_take_or_null() - more Pythonic naming."""
+        return self.random_take_or_null(n, random_instance)
+
+    def _weights(self, weight_selector: Callable[[T], float]) -> List[float]:
+        """Compute the weights of the elements for weighted random selection."""
+        if self.is_empty():
+            raise ValueError("List is empty")
+        weights = [weight_selector(e) for e in self._elements]
+        if any(w < 0 for w in weights):
+            raise ValueError("Weights must not be negative")
+        if sum(weights) <= 0:
+            raise ValueError("Total weight must be greater than zero")
+        return weights
+
+    def random_weighted(
+        self,
+        weight_selector: Callable[[T], float],
+        random_instance: Optional[_random.Random] = None
+    ) -> T:
+        """Returns a random element, chosen with probability proportional to weight_selector(element).
+
+        Raises:
+            ValueError: If this list is empty or all weights are zero.
+        """
+        return self.random_weighted_take(1, weight_selector, random_instance)[0]
+
+    def random_weighted_take(
+        self,
+        n: int,
+        weight_selector: Callable[[T], float],
+        random_instance: Optional[_random.Random] = None
+    ) -> 'KotList[T]':
+        """Returns a list of n randomly chosen elements (with replacement), weighted by weight_selector(element).
+
+        Raises:
+            ValueError: If n is negative, this list is empty or all weights are zero.
+        """
+        if n < 0:
+            raise ValueError("Requested element count is less than zero")
+        weights = self._weights(weight_selector)
+        rng = random_instance if random_instance else _random
+        return KotList(rng.choices(self._elements, weights=weights, k=n))
+
     # Sublist retrieval methods
     def slice(self, indices: Iterable[int]) -> 'KotList[T]':
         """Returns a list containing elements at specified indices."""
@@ -1016,6 +1904,15 @@ class KotList(Generic[T]):
                 break
         return KotList(result)
 
+    def take_while_indexed(self, predicate: Callable[[int, T], bool]) -> 'KotList[T]':
+        """Returns a list containing first elements satisfying the given predicate, which receives the index and element."""
+        end = len(self._elements)
+        for i, element in enumerate(self._elements):
+            if not predicate(i, element):
+                end = i
+                break
+        return self._with_element_type(self._elements[:end])
+
     def take_last_while(self, predicate: Callable[[T], bool]) -> 'KotList[T]':
         """Returns a list containing last elements satisfying the given predicate."""
         result = []
@@ -1032,6 +1929,21 @@ class KotList(Generic[T]):
             raise ValueError("Requested element count is less than zero")
         return KotList(self._elements[n:])
 
+    def split_at(self, index: int) -> Tuple['KotList[T]', 'KotList[T]']:
+        """Splits this list into the elements before index and the elements from index on.
+
+        The index is clamped to the list bounds: a negative index gives an empty prefix and an
+        index past the end gives the whole list followed by an empty suffix.
+
+        Examples:
+            >>> KotList([1, 2, 3, 4]).split_at(1)  # Returns (KotList([1]), KotList([2, 3, 4]))
+        """
+        index = min(max(TypeChecker.validate_index(index), 0), len(self._elements))
+        return (
+            self._with_element_type(self._elements[:index]),
+            self._with_element_type(self._elements[index:])
+        )
+
     def drop_last(self, n: int) -> 'KotList[T]':
         """Returns a list containing all elements except last n elements."""
         if n < 0:
@@ -1051,6 +1963,15 @@ class KotList(Generic[T]):
             return KotList()
         return KotList(self._elements[index:])
 
+    def drop_while_indexed(self, predicate: Callable[[int, T], bool]) -> 'KotList[T]':
+        """Returns a list without the first elements satisfying the given predicate, which receives the index and element."""
+        start = len(self._elements)
+        for i, element in enumerate(self._elements):
+            if not predicate(i, element):
+                start = i
+                break
+        return self._with_element_type(self._elements[start:])
+
     def drop_last_while(self, predicate: Callable[[T], bool]) -> 'KotList[T]':
         """Returns a list containing all elements except last elements that satisfy the given predicate."""
         index = len(self._elements)
@@ -1110,6 +2031,20 @@ class KotList(Generic[T]):
         """Returns the last element matching the given predicate, or null if no such element was found."""
         return self.last_or_null_predicate(predicate)
 
+    def find_or_else(self, predicate: Callable[[T], bool], default_value: Callable[[], T]) -> T:
+        """Returns the first element matching the given predicate, or the result of default_value() if none matches."""
+        for element in self._elements:
+            if predicate(element):
+                return element
+        return default_value()
+
+    def find_last_or_else(self, predicate: Callable[[T], bool], default_value: Callable[[], T]) -> T:
+        """Returns the last element matching the given predicate, or the result of default_value() if none matches."""
+        for element in reversed(self._elements):
+            if predicate(element):
+                return element
+        return default_value()
+
     def first_not_null_of(self, transform: Callable[[T], Optional[R]]) -> R:
         """Returns the first non-null value produced by transform function or throws NoSuchElementException."""
         for element in self._elements:
@@ -1135,17 +2070,27 @@ class KotList(Generic[T]):
         return self.first_not_null_of_or_null(transform)
 
     # Aggregation methods
-    def max_by(self, selector: Callable[[T], Any]) -> T:
-        """Returns the first element yielding the largest value of the given function."""
+    def max_by(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> T:
+        """Returns the first element yielding the largest value of the given function.
+
+        If tie_breaker is given, elements with equal selector values are compared by tie_breaker instead.
+        """
         if self.is_empty():
             raise ValueError("Cannot find max of empty list")
-        return max(self._elements, key=selector)
+        return max(self._elements, key=self._extreme_key(selector, tie_breaker))
+
+    def min_by(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> T:
+        """Returns the first element yielding the smallest value of the given function.
 
-    def min_by(self, selector: Callable[[T], Any]) -> T:
-        """Returns the first element yielding the smallest value of the given function."""
+        If tie_breaker is given, elements with equal selector values are compared by tie_breaker instead.
+        """
         if self.is_empty():
             raise ValueError("Cannot find min of empty list")
-        return min(self._elements, key=selector)
+        return min(self._elements, key=self._extreme_key(selector, tie_breaker))
 
     def max_of(self, selector: Callable[[T], Any]) -> Any:
         """Returns the largest value among all values produced by selector function."""
@@ -1324,6 +2269,12 @@ class KotList(Generic[T]):
         """Alias for reduce_or_null() - more Pythonic naming."""
         return self.reduce_or_null(operation)
 
+    def reduce_or_else(self, operation: Callable[[T, T], T], default_value: Callable[[], T]) -> T:
+        """Accumulates value starting with the first element, or returns the result of default_value() if the list is empty."""
+        if self.is_empty():
+            return default_value()
+        return self.reduce(operation)
+
     def running_fold(self, initial: R, operation: Callable[[R, T], R]) -> 'KotList[R]':
         """Returns a list containing successive accumulation values generated by applying operation from left to right."""
         # This is the same as scan, which is already implemented
@@ -1364,6 +2315,34 @@ class KotList(Generic[T]):
             result.append(acc)
         return KotList(result)
 
+    def cumulative_sum(self) -> 'KotList[Any]':
+        """Returns the running totals of this list, the same length as the list.
+
+        Integer input gives integer totals; once a float is involved the totals are floats.
+        """
+        return self._numeric_list(list(accumulate(self._elements)))
+
+    def cumulative_max(self) -> 'KotList[T]':
+        """Returns the running maximum of this list, the same length as the list.
+
+        Each entry is one of the original elements, so mixed int/float input is not converted to float.
+        """
+        return self._with_element_type(list(accumulate(self._elements, max)))
+
+    def cumulative_min(self) -> 'KotList[T]':
+        """Returns the running minimum of this list, the same length as the list.
+
+        Each entry is one of the original elements, so mixed int/float input is not converted to float.
+        """
+        return self._with_element_type(list(accumulate(self._elements, min)))
+
+    @staticmethod
+    def _numeric_list(values: List[Any]) -> 'KotList[Any]':
+        """Create a KotList of numbers, promoting ints to floats when the values mix both."""
+        if any(isinstance(v, float) for v in values) and all(isinstance(v, (int, float)) for v in values):
+            values = [float(v) for v in values]
+        return KotList(values)
+
     # Other methods
     def as_reversed(self) -> 'KotList[T]':
         """Returns a reversed read-only view of the original List."""
@@ -1386,9 +2365,24 @@ class KotList(Generic[T]):
             action(i, element)
         return self
 
-    def if_empty(self, default_value: Callable[[], 'KotList[T]']) -> 'KotList[T]':
-        """Returns this list if it's not empty or the result of calling defaultValue function if the list is empty."""
-        return self if self.is_not_empty() else default_value()
+    def if_empty(self, default_value: Union[Callable[[], R], R]) -> Union['KotList[T]', R]:
+        """Returns this list if it's not empty, otherwise the default value.
+
+        default_value may be a function producing the replacement (called only when the list is empty)
+        or the replacement value itself, e.g. if_empty(lambda: KotList[T](...)) or if_empty(KotList[T](...)).
+        """
+        if self.is_not_empty():
+            return self
+        return default_value() if callable(default_value) else default_value
+
+    def if_not_empty(self, default_value: Union[Callable[[], R], R]) -> Union['KotList[T]', R]:
+        """Returns this list if it's empty, otherwise the default value - the mirror of if_empty().
+
+        Like if_empty(), default_value may be a function or the replacement value itself.
+        """
+        if self.is_empty():
+            return self
+        return default_value() if callable(default_value) else default_value
 
     # ListIterator methods
     def list_iterator(self, index: int = 0) -> Iterator[T]:
@@ -4,7 +4,9 @@ KotMap: A Python implementation of Kotlin's Map interface with snake_case naming
 
 from __future__ import annotations
 
-from typing import TypeVar, Generic, Callable, Optional, Dict, Iterator, Any, Tuple, List, Set, Type, TYPE_CHECKING
+from collections import OrderedDict
+from itertools import islice
+from typing import TypeVar, Generic, Callable, Optional, Dict, Iterable, Iterator, Any, Tuple, List, Set, Type, TYPE_CHECKING, Union
 
 from kotcollections.type_checker import TypeChecker
 
@@ -16,7 +18,6 @@ V = TypeVar('V')
 R = TypeVar('R')
 
 
-
 class KotMap(Generic[K, V]):
     """A Python implementation of Kotlin's Map interface.
     
@@ -24,26 +25,43 @@ class KotMap(Generic[K, V]):
     maintaining type safety and immutability.
     """
 
-    def __init__(self, elements: Optional[Dict[K, V] | List[Tuple[K, V]] | Iterator[Tuple[K, V]]] = None):
+    # True when the key/value type was declared via of_type() or KotMap[K, V], not inferred from an entry
+    _key_type_declared: bool = False
+    _value_type_declared: bool = False
+
+    def __init__(
+        self,
+        elements: Optional[Dict[K, V] | List[Tuple[K, V]] | Iterator[Tuple[K, V]]] = None,
+        strict: bool = False
+    ):
         """Initialize a KotMap with optional elements.
-        
+
+        Duplicate keys in a list or iterator of pairs are collapsed like in a dict:
+        the last value wins and the key keeps its first position.
+
         Args:
             elements: Initial elements for the map (dict, list of tuples, or iterator of tuples)
+            strict: If True, raise ValueError on duplicate keys instead of keeping the last value
         """
         self._elements: Dict[K, V] = {}
         self._key_type: Optional[type] = None
         self._value_type: Optional[type] = None
 
-        if elements is not None:
-            if isinstance(elements, dict):
-                for key, value in elements.items():
-                    self._put_with_type_check(key, value)
-            elif isinstance(elements, list):
-                for key, value in elements:
-                    self._put_with_type_check(key, value)
-            else:  # Iterator
-                for key, value in elements:
-                    self._put_with_type_check(key, value)
+        self._init_elements(elements, strict)
+
+    def _init_elements(
+        self,
+        elements: Optional[Dict[K, V] | List[Tuple[K, V]] | Iterator[Tuple[K, V]]],
+        strict: bool
+    ) -> None:
+        """Populate the map from the constructor elements, optionally rejecting duplicate keys."""
+        if elements is None:
+            return
+        pairs = elements.items() if isinstance(elements, dict) else elements
+        for key, value in pairs:
+            if strict and key in self._elements:
+                raise ValueError(f"Duplicate key: {key!r}")
+            self._put_with_type_check(key, value)
 
     @classmethod
     def __class_getitem__(cls, types: Tuple[Type[K], Type[V]]) -> Type['KotMap[K, V]']:
@@ -57,22 +75,15 @@ class KotMap(Generic[K, V]):
         key_type, value_type = types
 
         class TypedKotMap(cls):
-            def __init__(self, elements=None):
+            def __init__(self, elements=None, strict=False):
                 # Only set types if they are actual types, not type variables
                 self._elements = {}
                 self._key_type = key_type if isinstance(key_type, type) else None
                 self._value_type = value_type if isinstance(value_type, type) else None
+                self._key_type_declared = self._key_type is not None
+                self._value_type_declared = self._value_type is not None
                 # Now process elements with the correct types set
-                if elements is not None:
-                    if isinstance(elements, dict):
-                        for key, value in elements.items():
-                            self._put_with_type_check(key, value)
-                    elif isinstance(elements, list):
-                        for key, value in elements:
-                            self._put_with_type_check(key, value)
-                    else:  # Iterator
-                        for key, value in elements:
-                            self._put_with_type_check(key, value)
+                self._init_elements(elements, strict)
 
         # Set a meaningful name for debugging (handle cases where __name__ might not exist)
         key_type_name = getattr(key_type, '__name__', str(key_type))
@@ -121,10 +132,94 @@ class KotMap(Generic[K, V]):
             >>> animals_by_name = KotMap[str, Animal](
             ...     [("Buddy", Dog("Buddy")), ("Whiskers", Cat("Whiskers"))])
         """
+        TypeChecker.validate_declared_type(key_type, "KotMap")
+        TypeChecker.validate_declared_type(value_type, "KotMap")
         # Use __class_getitem__ to create the same dynamic subclass
         typed_class = cls[key_type, value_type]
         return typed_class(elements)
 
+    @classmethod
+    def _of_types(
+        cls,
+        key_type: Optional[type],
+        value_type: Optional[type],
+        elements: Any,
+        key_type_declared: bool,
+        value_type_declared: bool,
+    ) -> 'KotMap[K, V]':
+        """Create a map of the given elements with the given types, recording which of them were declared.
+
+        A type of None is inferred from the elements; when both are None an untyped map is created.
+        """
+        if key_type is None and value_type is None:
+            return cls(elements)
+        result = cls[key_type, value_type](elements)
+        result._key_type_declared = key_type_declared
+        result._value_type_declared = value_type_declared
+        return result
+
+    @classmethod
+    def empty_of_types(cls, key_type: Type[K], value_type: Type[V]) -> 'KotMap[K, V]':
+        """Create an empty KotMap whose key and value types are fixed up front.
+
+        Unlike an empty ``KotMap()``, which infers its types from the first entry,
+        the returned map type-checks every key and value against the given types.
+        """
+        return cls.of_type(key_type, value_type)
+
+    @classmethod
+    def or_empty(cls, value: Optional[Dict[K, V] | Iterable[Tuple[K, V]]]) -> 'KotMap[K, V]':
+        """Returns an empty KotMap if value is None, like Kotlin's orEmpty().
+
+        A KotMap is returned as is; any other value is passed to the constructor.
+
+        Examples:
+            >>> KotMap.or_empty(None)  # Returns an empty map
+        """
+        if value is None:
+            return cls()
+        if isinstance(value, cls):
+            return value
+        return cls(value)
+
+    @classmethod
+    def of_kwargs(cls, **kwargs: V) -> 'KotMap[str, V]':
+        """Create a string-keyed map from keyword arguments.
+
+        The key type is inferred as str and the value type from the first value.
+
+        Examples:
+            >>> config = KotMap.of_kwargs(host="localhost", port="8080")
+            >>> config.get("host")  # Returns 'localhost'
+        """
+        return cls(kwargs)
+
+    @classmethod
+    def merge_maps(
+        cls,
+        maps: Iterable[Dict[K, V] | 'KotMap[K, V]'],
+        resolver: Optional[Callable[[K, V, V], V]] = None
+    ) -> 'KotMap[K, V]':
+        """Merge several maps or dicts left to right into a new map.
+
+        Keys keep the position of their first appearance. When a key is already present,
+        resolver(key, existing, new) decides the value; without a resolver the last value wins.
+
+        Examples:
+            >>> defaults = {"host": "localhost", "port": 80}
+            >>> overrides = KotMap({"port": 8080})
+            >>> KotMap.merge_maps([defaults, overrides])  # KotMap({'host': 'localhost', 'port': 8080})
+        """
+        result: Dict[K, V] = {}
+        for m in maps:
+            items = m._elements.items() if isinstance(m, KotMap) else m.items()
+            for key, value in items:
+                if resolver is not None and key in result:
+                    result[key] = resolver(key, result[key], value)
+                else:
+                    result[key] = value
+        return cls(result)
+
     def _put_with_type_check(self, key: K, value: V) -> None:
         """Add a key-value pair with type checking.
 
@@ -175,8 +270,50 @@ class KotMap(Generic[K, V]):
 
     def contains_value(self, value: V) -> bool:
         """Returns true if the map maps one or more keys to the specified value."""
+        index = self._value_index()
+        if index is not None:
+            try:
+                return value in index
+            except TypeError:
+                pass
         return value in self._elements.values()
 
+    def contains_entry(self, key: K, value: V) -> bool:
+        """Returns true if the map contains the specified key and it is mapped to a value equal to the specified one."""
+        if key not in self._elements:
+            return False
+        return self._elements[key] == value
+
+    def keys_for_value(self, value: V) -> 'KotList[K]':
+        """Returns a list of all keys mapped to the specified value, in iteration order."""
+        from kotcollections.kot_list import KotList
+        keys = None
+        index = self._value_index()
+        if index is not None:
+            try:
+                keys = index.get(value, [])
+            except TypeError:
+                pass
+        if keys is None:
+            keys = [k for k, v in self._elements.items() if v == value]
+        return KotList(keys)
+
+    def _value_index(self) -> Optional[Dict[V, List[K]]]:
+        """Lazily build a reverse index from values to keys.
+
+        Returns None if any value is unhashable, in which case lookups fall back to a linear scan.
+        The index is cached since KotMap is immutable.
+        """
+        if not hasattr(self, '_cached_value_index'):
+            index: Optional[Dict[V, List[K]]] = {}
+            try:
+                for k, v in self._elements.items():
+                    index.setdefault(v, []).append(k)
+            except TypeError:
+                index = None
+            self._cached_value_index = index
+        return self._cached_value_index
+
     # Access operations
 
     def get(self, key: K) -> Optional[V]:
@@ -202,11 +339,61 @@ class KotMap(Generic[K, V]):
         return self.get_or_null(key)
 
     def get_value(self, key: K) -> V:
-        """Returns the value for the given key or throws an exception if the key is missing in the map."""
+        """Returns the value for the given key or throws an exception if the key is missing in the map.
+
+        Raises:
+            KeyError: If the key is missing, the counterpart of Kotlin's NoSuchElementException.
+        """
         if key not in self._elements:
             raise KeyError(f"Key {key} is missing in the map.")
         return self._elements[key]
 
+    def get_path(self, *keys: Any) -> Optional[Any]:
+        """Returns the value found by walking the given keys through nested maps, or null if any level is missing.
+
+        Each key is looked up in the current map; nested KotMap and dict values are descended into.
+        If a key is missing or an intermediate value is not a map, null is returned.
+
+        Examples:
+            >>> config = KotMap({"db": KotMap({"host": "localhost"})})
+            >>> config.get_path("db", "host")  # Returns "localhost"
+            >>> config.get_path("db", "port")  # Returns None
+        """
+        current: Any = self
+        for key in keys:
+            if isinstance(current, KotMap):
+                current = current._elements
+            if not isinstance(current, dict) or key not in current:
+                return None
+            current = current[key]
+        return current
+
+    def values_at(self, keys: Iterable[K], strict: bool = False) -> 'KotList[Optional[V]]':
+        """Returns a list of the values for the given keys, in the order the keys are given.
+
+        Missing keys yield None, or raise KeyError when strict is True. Since KotList does not
+        allow None alongside other types, any result with a missing key is created as KotList[object].
+
+        Examples:
+            >>> m = KotMap({"a": 1, "b": 2, "c": 3})
+            >>> m.values_at(["c", "a"])  # Returns KotList([3, 1])
+            >>> m.values_at(["a", "x"])  # Returns KotList[object]([1, None])
+        """
+        from kotcollections import KotList
+        result = []
+        missing = False
+        for key in keys:
+            if key in self._elements:
+                result.append(self._elements[key])
+            elif strict:
+                raise KeyError(f"Key {key} is missing in the map.")
+            else:
+                result.append(None)
+                missing = True
+        if missing:
+            return KotList.of_type(object, result)
+        return KotList._of_element_type(self._value_type, result, self._value_type_declared)
+
     # Collection views
 
     @property
@@ -227,6 +414,80 @@ class KotMap(Generic[K, V]):
         from kotcollections import KotSet
         return KotSet(list(self._elements.items()))
 
+    def keys_list(self) -> 'KotList[K]':
+        """Returns a KotList of all keys in insertion order."""
+        from kotcollections import KotList
+        return KotList._of_element_type(self._key_type, self._elements.keys(), self._key_type_declared)
+
+    def entries_list(self) -> 'KotList[Tuple[K, V]]':
+        """Returns a KotList of all (key, value) pairs in insertion order."""
+        from kotcollections import KotList
+        return KotList(self._elements.items())
+
+    def entries_iterator(self) -> Iterator[Tuple[K, V]]:
+        """Returns an iterator over (key, value) pairs in insertion order.
+
+        Iterating the map itself yields keys, following the dict convention; this mirrors
+        Kotlin's `for ((k, v) in map)` instead.
+
+        Examples:
+            >>> for k, v in KotMap({"a": 1}).entries_iterator():
+            ...     print(k, v)  # Prints "a 1"
+        """
+        return iter(self._elements.items())
+
+    def entry_at(self, index: int) -> Tuple[K, V]:
+        """Returns the (key, value) pair at the given position in insertion order.
+
+        Entries are plain tuples, so they destructure like Kotlin's component1()/component2()
+        and already serve as the result of toPair().
+
+        Raises:
+            TypeError: If the index is not an integer.
+            IndexError: If the index is negative or not less than the size of the map.
+
+        Examples:
+            >>> key, value = KotMap({"a": 1, "b": 2}).entry_at(1)  # key == "b", value == 2
+        """
+        index = TypeChecker.validate_index(index)
+        if index < 0 or index >= len(self._elements):
+            raise IndexError(f"Index {index} out of bounds for map of size {len(self._elements)}")
+        return next(islice(self._elements.items(), index, None))
+
+    def sorted_entries(
+        self,
+        by: str = "key",
+        comparator: Optional[Callable[[Any, Any], int]] = None
+    ) -> 'KotList[Tuple[K, V]]':
+        """Returns a KotList of all (key, value) pairs sorted by key or by value.
+
+        Args:
+            by: "key" to sort by keys, or "value" to sort by values
+            comparator: Optional comparator applied to the selected keys or values;
+                        natural order is used if omitted
+
+        Raises:
+            ValueError: If by is neither "key" nor "value"
+
+        Examples:
+            >>> m = KotMap({"b": 1, "a": 2})
+            >>> m.sorted_entries()  # Returns KotList([('a', 2), ('b', 1)])
+            >>> m.sorted_entries(by="value")  # Returns KotList([('b', 1), ('a', 2)])
+        """
+        from kotcollections.kot_list import KotList, _checked_comparator
+        if by == "key":
+            index = 0
+        elif by == "value":
+            index = 1
+        else:
+            raise ValueError(f"by must be 'key' or 'value', got {by!r}")
+        if comparator is not None:
+            compare_key = _checked_comparator(comparator)
+            sort_key = lambda entry: compare_key(entry[index])
+        else:
+            sort_key = lambda entry: entry[index]
+        return KotList(sorted(self._elements.items(), key=sort_key))
+
     # Checking operations
 
     def all(self, predicate: Callable[[K, V], bool]) -> bool:
@@ -247,6 +508,32 @@ class KotMap(Generic[K, V]):
             return self.size
         return sum(1 for k, v in self._elements.items() if predicate(k, v))
 
+    def require_keys_instance(self, klass: Type[R]) -> 'KotMap[K, V]':
+        """Returns this map if every key is an instance of klass.
+
+        Raises:
+            TypeError: If any key is not an instance of klass
+        """
+        for key in self._elements:
+            if not isinstance(key, klass):
+                raise TypeError(
+                    f"Key {key!r} of type '{type(key).__name__}' is not an instance of '{klass.__name__}'"
+                )
+        return self
+
+    def require_values_instance(self, klass: Type[R]) -> 'KotMap[K, V]':
+        """Returns this map if every value is an instance of klass.
+
+        Raises:
+            TypeError: If any value is not an instance of klass
+        """
+        for key, value in self._elements.items():
+            if not isinstance(value, klass):
+                raise TypeError(
+                    f"Value for key {key!r} of type '{type(value).__name__}' is not an instance of '{klass.__name__}'"
+                )
+        return self
+
     # Filtering operations
 
     def filter(self, predicate: Callable[[K, V], bool]) -> 'KotMap[K, V]':
@@ -286,10 +573,27 @@ class KotMap(Generic[K, V]):
         return KotList([transform(k, v) for k, v in self._elements.items()])
 
     def map_keys(self, transform: Callable[[K, V], R]) -> 'KotMap[R, V]':
-        """Returns a new map with entries having the keys obtained by applying the transform function to each entry."""
+        """Returns a new map with entries having the keys obtained by applying the transform function to each entry.
+
+        If any two entries are mapped to equal keys, the value of the latter one overwrites
+        the value associated with the former one (the key keeps its first position).
+        """
         transformed_pairs = [(transform(k, v), v) for k, v in self._elements.items()]
         return KotMap(transformed_pairs)
 
+    def map_keys_not_null(self, transform: Callable[[K, V], Optional[R]]) -> 'KotMap[R, V]':
+        """Like map_keys(), but drops entries whose transformed key is null."""
+        transformed_pairs = []
+        for k, v in self._elements.items():
+            new_key = transform(k, v)
+            if new_key is not None:
+                transformed_pairs.append((new_key, v))
+        return KotMap(transformed_pairs)
+
+    def map_keys_not_none(self, transform: Callable[[K, V], Optional[R]]) -> 'KotMap[R, V]':
+        """Pythonic alias for map_keys_not_null()."""
+        return self.map_keys_not_null(transform)
+
     def map_values(self, transform: Callable[[K, V], R]) -> 'KotMap[K, R]':
         """Returns a new map with entries having the values obtained by applying the transform function to each entry."""
         transformed_pairs = [(k, transform(k, v)) for k, v in self._elements.items()]
@@ -317,6 +621,22 @@ class KotMap(Generic[K, V]):
             results.extend(transform(k, v))
         return KotList(results)
 
+    def flat_map_values(self, transform: Optional[Callable[[K, V], Iterator[R]]] = None) -> 'KotList[R]':
+        """Returns a single list of all elements of the values, or of the results of transform if given.
+
+        Examples:
+            >>> buckets = KotMap({"a": [1, 2], "b": [3]})
+            >>> buckets.flat_map_values()  # Returns KotList([1, 2, 3])
+            >>> buckets.flat_map_values(lambda k, v: [k] * len(v))  # Returns KotList(['a', 'a', 'b'])
+        """
+        if transform is None:
+            from kotcollections import KotList
+            results = []
+            for v in self._elements.values():
+                results.extend(v)
+            return KotList(results)
+        return self.flat_map(transform)
+
     # Conversion operations
 
     def to_list(self) -> List[Tuple[K, V]]:
@@ -327,23 +647,24 @@ class KotMap(Generic[K, V]):
         """Returns a Python dict containing all key-value pairs."""
         return dict(self._elements.copy())
 
+    def to_ordered_dict(self) -> 'OrderedDict[K, V]':
+        """Returns a collections.OrderedDict containing all key-value pairs in insertion order."""
+        return OrderedDict(self._elements)
+
     def to_kot_map(self) -> 'KotMap[K, V]':
         """Returns a KotMap containing all key-value pairs."""
-        # Preserve type information when converting
-        if self._key_type is not None and self._value_type is not None:
-            return KotMap.of_type(self._key_type, self._value_type, self._elements.copy())
-        else:
-            return KotMap(self._elements.copy())
+        # Preserve type information when converting, even if only one of the types is known
+        return KotMap._of_types(
+            self._key_type, self._value_type, self._elements.copy(), self._key_type_declared, self._value_type_declared
+        )
 
     def to_kot_mutable_map(self) -> 'KotMutableMap[K, V]':
         """Returns a KotMutableMap containing all key-value pairs."""
         from kotcollections.kot_mutable_map import KotMutableMap
-        # Preserve type information when converting
-        if self._key_type is not None and self._value_type is not None:
-            mutable_map = KotMutableMap.of_type(self._key_type, self._value_type, self._elements.copy())
-        else:
-            mutable_map = KotMutableMap(self._elements.copy())
-        return mutable_map
+        # Preserve type information when converting, even if only one of the types is known
+        return KotMutableMap._of_types(
+            self._key_type, self._value_type, self._elements.copy(), self._key_type_declared, self._value_type_declared
+        )
 
     # Action operations
 
@@ -358,6 +679,30 @@ class KotMap(Generic[K, V]):
             action(k, v)
         return self
 
+    def with_index(self) -> 'KotList[Tuple[int, Tuple[K, V]]]':
+        """Returns a KotList of (index, (key, value)) pairs for each entry in insertion order."""
+        from kotcollections.kot_list import KotList
+        return KotList(enumerate(self._elements.items()))
+
+    def if_empty(self, default_value: Union[Callable[[], R], R]) -> Union['KotMap[K, V]', R]:
+        """Returns this map if it's not empty, otherwise the default value.
+
+        default_value may be a function producing the replacement (called only when the map is empty)
+        or the replacement value itself, e.g. if_empty(lambda: KotMap[K, V](...)) or if_empty(KotMap[K, V](...)).
+        """
+        if self.is_not_empty():
+            return self
+        return default_value() if callable(default_value) else default_value
+
+    def if_not_empty(self, default_value: Union[Callable[[], R], R]) -> Union['KotMap[K, V]', R]:
+        """Returns this map if it's empty, otherwise the default value - the mirror of if_empty().
+
+        Like if_empty(), default_value may be a function or the replacement value itself.
+        """
+        if self.is_empty():
+            return self
+        return default_value() if callable(default_value) else default_value
+
     # Finding operations
 
     def max_by(self, selector: Callable[[K, V], Any]) -> Optional[Tuple[K, V]]:
@@ -476,9 +821,49 @@ class KotMap(Generic[K, V]):
         """Iterate over keys."""
         return iter(self._elements)
 
+    def is_key_type_declared(self) -> bool:
+        """Returns true if the key type was declared via of_type() or KotMap[K, V] rather than inferred."""
+        return self._key_type_declared and self._key_type is not None
+
+    def is_value_type_declared(self) -> bool:
+        """Returns true if the value type was declared via of_type() or KotMap[K, V] rather than inferred."""
+        return self._value_type_declared and self._value_type is not None
+
+    def _declared_types_suffix(self) -> str:
+        """Return "[key, value]" for use in repr when a type was declared, or "" otherwise.
+
+        A side whose type was not declared is shown as Any.
+        """
+        key_declared = self.is_key_type_declared()
+        value_declared = self.is_value_type_declared()
+        if not (key_declared or value_declared):
+            return ""
+        key_name = self._key_type.__name__ if key_declared else "Any"
+        value_name = self._value_type.__name__ if value_declared else "Any"
+        return f"[{key_name}, {value_name}]"
+
     def __repr__(self) -> str:
-        """Return string representation of the map."""
-        return f"KotMap({dict(self._elements)})"
+        """Return string representation of the map, including the key and value types if they were declared."""
+        return f"KotMap{self._declared_types_suffix()}({dict(self._elements)})"
+
+    def __format__(self, format_spec: str) -> str:
+        """Format the map, using a non-empty format spec as the separator between key=value entries."""
+        if not format_spec:
+            return str(self)
+        return self.join_to_string(separator=format_spec)
+
+    def equals_native(self, other: Any) -> bool:
+        """Returns true if this map is structurally equal to other once all Kot collections are unwrapped.
+
+        Nested KotList, KotSet and KotMap values compare equal to native lists, sets and dicts with the same
+        contents, which makes assertions against plain Python literals straightforward. Unlike ==, the
+        comparison ignores the Kot wrapping entirely.
+
+        Examples:
+            >>> KotMap({"a": KotList([1])}).equals_native({"a": [1]})  # True
+        """
+        from kotcollections.native_equality import native_equals
+        return native_equals(self, other)
 
     def __eq__(self, other: Any) -> bool:
         """Check equality with another KotMap."""
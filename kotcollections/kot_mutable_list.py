@@ -1,10 +1,11 @@
 from __future__ import annotations
 
+import bisect
 import random
-from functools import cmp_to_key
-from typing import TypeVar, Optional, Callable, Iterable, List, Type
+from typing import TypeVar, Optional, Callable, Iterable, List, Set, Type, Union
 
-from kotcollections.kot_list import KotList
+from kotcollections.kot_list import KotList, _checked_comparator
+from kotcollections.type_checker import TypeChecker
 
 T = TypeVar('T')
 
@@ -153,6 +154,7 @@ class KotMutableList(KotList[T]):
                     self._element_type = element_type
                 else:
                     self._element_type = None
+                self._type_declared = self._element_type is not None
                 self._elements = []
                 # Now process elements with the correct type set
                 if elements is not None:
@@ -200,10 +202,35 @@ class KotMutableList(KotList[T]):
             >>> animals = KotMutableList.of_type(Animal, [Dog("Buddy")])
             >>> animals.add(Cat("Whiskers"))  # Type-checked at runtime
         """
+        TypeChecker.validate_declared_type(element_type, "KotMutableList")
         # Use __class_getitem__ to create the same dynamic subclass
         typed_class = cls[element_type]
         return typed_class(elements)
 
+    def snapshot(self) -> KotList[T]:
+        """Returns an immutable copy of the current elements, safe to iterate while this list is mutated."""
+        return self.to_kot_list()
+
+    def to_kot_list(self) -> KotList[T]:
+        """Returns a read-only KotList of the current elements.
+
+        Unlike KotList.to_kot_list(), the elements are copied so later mutations don't leak into the result.
+        """
+        return self._with_element_type(self._elements.copy())
+
+    def __repr__(self) -> str:
+        return f"KotMutableList{self._declared_type_suffix()}({self._elements})"
+
+    def _element_index(self) -> Optional[Set[T]]:
+        """Mutable lists do not cache an element index; membership checks always scan the elements."""
+        return None
+
+    def __getitem__(self, index: Union[int, slice]) -> Union[T, 'KotMutableList[T]']:
+        """Return the element at index, or a new KotMutableList with the source element type for a slice."""
+        if isinstance(index, slice):
+            return KotMutableList._of_element_type(self._element_type, self._elements[index], self._type_declared)
+        return self.get(index)
+
     def __setitem__(self, index: int, value: T) -> None:
         self.set(index, value)
 
@@ -216,33 +243,50 @@ class KotMutableList(KotList[T]):
         return True
 
     def add_at(self, index: int, element: T) -> None:
+        index = TypeChecker.validate_index(index)
         if not 0 <= index <= self.size:
             raise IndexError(f"Index {index} out of bounds for insertion")
         self._check_type(element)
         self._elements.insert(index, element)
 
+    def _checked_elements(self, elements: Iterable[T]) -> List[T]:
+        """Return the elements as a list after type-checking each one against this list.
+
+        A KotList or KotSet source whose element type is a subclass of this list's element type
+        is already known to match, so its backing store is copied without per-element checks.
+        """
+        from kotcollections.kot_set import KotSet
+        if isinstance(elements, (KotList, KotSet)):
+            elements_list = list(elements._elements)
+            source_type = elements._element_type
+            if (isinstance(source_type, type) and isinstance(self._element_type, type)
+                    and issubclass(source_type, self._element_type)):
+                return elements_list
+        else:
+            elements_list = list(elements)
+        for element in elements_list:
+            self._check_type(element)
+        return elements_list
+
     def add_all(self, elements: Iterable[T]) -> bool:
-        elements_list = list(elements)
+        elements_list = self._checked_elements(elements)
         if elements_list:
-            for element in elements_list:
-                self._check_type(element)
             self._elements.extend(elements_list)
             return True
         return False
 
     def add_all_at(self, index: int, elements: Iterable[T]) -> bool:
+        index = TypeChecker.validate_index(index)
         if not 0 <= index <= self.size:
             raise IndexError(f"Index {index} out of bounds for insertion")
-        elements_list = list(elements)
+        elements_list = self._checked_elements(elements)
         if elements_list:
-            for element in elements_list:
-                self._check_type(element)
-            for i, element in enumerate(elements_list):
-                self._elements.insert(index + i, element)
+            self._elements[index:index] = elements_list
             return True
         return False
 
     def set(self, index: int, element: T) -> T:
+        index = TypeChecker.validate_index(index)
         if not 0 <= index < self.size:
             raise IndexError(f"Index {index} out of bounds for list of size {self.size}")
         self._check_type(element)
@@ -250,7 +294,33 @@ class KotMutableList(KotList[T]):
         self._elements[index] = element
         return old_element
 
+    def set_all(self, from_index: int, elements: Iterable[T]) -> int:
+        """Replaces the elements starting at from_index with the given elements.
+
+        All elements are type-checked before anything is written, so a failure leaves the list unchanged.
+
+        Returns:
+            The number of elements written.
+
+        Raises:
+            IndexError: If from_index is out of bounds or the elements would extend past the end of the list.
+        """
+        from_index = TypeChecker.validate_index(from_index)
+        new_elements = list(elements)
+        if not 0 <= from_index <= self.size:
+            raise IndexError(f"Index {from_index} out of bounds for list of size {self.size}")
+        if from_index + len(new_elements) > self.size:
+            raise IndexError(
+                f"Cannot write {len(new_elements)} elements at index {from_index} in list of size {self.size}"
+            )
+        for element in new_elements:
+            self._check_type(element)
+        for offset, element in enumerate(new_elements):
+            self.set(from_index + offset, element)
+        return len(new_elements)
+
     def remove_at(self, index: int) -> T:
+        index = TypeChecker.validate_index(index)
         if not 0 <= index < self.size:
             raise IndexError(f"Index {index} out of bounds for list of size {self.size}")
         return self._elements.pop(index)
@@ -300,6 +370,40 @@ class KotMutableList(KotList[T]):
         """Alias for remove_last_or_null() - more Pythonic naming."""
         return self.remove_last_or_null()
 
+    # Stack and queue aliases
+
+    def peek_first(self) -> T:
+        """Alias for first() - returns the first element without removing it, for queue use."""
+        return self.first()
+
+    def peek_last(self) -> T:
+        """Alias for last() - returns the last element without removing it, for stack use."""
+        return self.last()
+
+    def peek_first_or_null(self) -> Optional[T]:
+        """Alias for first_or_null() - returns the first element without removing it, or null if empty."""
+        return self.first_or_null()
+
+    def peek_first_or_none(self) -> Optional[T]:
+        """Alias for peek_first_or_null() - more Pythonic naming."""
+        return self.peek_first_or_null()
+
+    def peek_last_or_null(self) -> Optional[T]:
+        """Alias for last_or_null() - returns the last element without removing it, or null if empty."""
+        return self.last_or_null()
+
+    def peek_last_or_none(self) -> Optional[T]:
+        """Alias for peek_last_or_null() - more Pythonic naming."""
+        return self.peek_last_or_null()
+
+    def push(self, element: T) -> bool:
+        """Alias for add() - pushes the element onto the end of the list, for stack use."""
+        return self.add(element)
+
+    def pop(self) -> T:
+        """Alias for remove_last() - removes and returns the last element, for stack use."""
+        return self.remove_last()
+
     def retain_all(self, elements: Iterable[T]) -> bool:
         elements_set = set(elements)
         initial_size = self.size
@@ -322,6 +426,20 @@ class KotMutableList(KotList[T]):
     def clear(self) -> None:
         self._elements.clear()
 
+    def drain(self) -> KotList[T]:
+        """Removes all elements and returns them as a KotList, preserving the element type.
+
+        Useful for swapping out a batch in producer/consumer buffers. This list keeps its element type,
+        and on a sub_list view only the view's range is removed from the parent list.
+
+        Examples:
+            >>> buffer = KotMutableList([1, 2])
+            >>> buffer.drain()  # Returns KotList([1, 2]); buffer is now empty
+        """
+        drained = self.to_kot_list()
+        self.clear()
+        return drained
+
     def sort(self, key: Optional[Callable[[T], any]] = None, reverse: bool = False) -> None:
         self._elements.sort(key=key, reverse=reverse)
 
@@ -334,9 +452,49 @@ class KotMutableList(KotList[T]):
     def sort_by_descending(self, selector: Callable[[T], any]) -> None:
         self._elements.sort(key=selector, reverse=True)
 
+    def sort_by_with_tiebreak(
+        self,
+        primary_selector: Callable[[T], any],
+        secondary_selector: Callable[[T], any]
+    ) -> None:
+        """Sorts elements in place by primary_selector, ordering ties by secondary_selector.
+
+        Each selector is called once per element to build a tuple key, so no comparator is needed.
+        """
+        self._elements = sorted(
+            self._elements,
+            key=lambda element: (primary_selector(element), secondary_selector(element))
+        )
+
     def sort_with(self, comparator: Callable[[T, T], int]) -> None:
-        """Sorts elements in the list in-place according to the specified comparator."""
-        self._elements.sort(key=cmp_to_key(comparator))
+        """Sorts elements in the list in-place according to the specified comparator.
+
+        Raises:
+            TypeError: If the comparator returns something other than a number.
+        """
+        self._elements.sort(key=_checked_comparator(comparator))
+
+    def insert_sorted(self, element: T, comparator: Optional[Callable[[T, T], int]] = None) -> int:
+        """Inserts the element at the position that keeps this sorted list sorted.
+
+        The insertion point is found by binary search, using natural order or the given comparator.
+        The element is placed after any elements that compare equal to it.
+
+        Returns:
+            The index at which the element was inserted.
+
+        Examples:
+            >>> lst = KotMutableList([1, 3, 5])
+            >>> lst.insert_sorted(4)  # Returns 2; lst is now [1, 3, 4, 5]
+        """
+        self._check_type(element)
+        if comparator is None:
+            index = bisect.bisect_right(self._elements, element)
+        else:
+            key = _checked_comparator(comparator)
+            index = bisect.bisect_right(self._elements, key(element), key=key)
+        self.add_at(index, element)
+        return index
 
     def reverse(self) -> None:
         self._elements.reverse()
@@ -347,6 +505,10 @@ class KotMutableList(KotList[T]):
         else:
             random.shuffle(self._elements)
 
+    def rotate(self, n: int) -> None:
+        """Rotates the elements in place right by n positions, or left if n is negative (modulo the size)."""
+        self._elements = self._rotated_elements(n)
+
     def fill(self, value: T) -> None:
         for i in range(self.size):
             self._elements[i] = value
@@ -429,6 +591,8 @@ class KotMutableList(KotList[T]):
             >>> lst.to_list()
             [1, 10, 3, 4, 5]
         """
+        from_index = TypeChecker.validate_index(from_index)
+        to_index = TypeChecker.validate_index(to_index)
         if from_index < 0 or to_index > self.size:
             raise IndexError(f"fromIndex {from_index} or toIndex {to_index} out of bounds for list of size {self.size}")
         if from_index > to_index:
@@ -452,23 +616,48 @@ class KotMutableList(KotList[T]):
                 self._parent._elements[self._start:self._end] = value
                 self._end = self._start + len(value)
 
+            @property
+            def _element_type(self) -> Optional[type]:
+                """Return the parent's element type."""
+                return self._parent._element_type
+
+            @_element_type.setter
+            def _element_type(self, value: Optional[type]) -> None:
+                """Update the parent's element type."""
+                self._parent._element_type = value
+
+            @property
+            def _type_declared(self) -> bool:
+                """Return whether the parent's element type was declared."""
+                return self._parent._type_declared
+
+            @_type_declared.setter
+            def _type_declared(self, value: bool) -> None:
+                """Update whether the parent's element type was declared."""
+                self._parent._type_declared = value
+
             @property
             def size(self) -> int:
                 """Return the size of the sublist."""
                 return self._end - self._start
 
-            def __getitem__(self, index: int) -> T:
+            def __getitem__(self, index: Union[int, slice]) -> Union[T, 'KotMutableList[T]']:
+                if isinstance(index, slice):
+                    return super().__getitem__(index)
+                index = TypeChecker.validate_index(index)
                 if not 0 <= index < self.size:
                     raise IndexError(f"Index {index} out of bounds for sublist of size {self.size}")
                 return self._parent._elements[self._start + index]
 
             def __setitem__(self, index: int, value: T) -> None:
+                index = TypeChecker.validate_index(index)
                 if not 0 <= index < self.size:
                     raise IndexError(f"Index {index} out of bounds for sublist of size {self.size}")
                 self._parent._check_type(value)
                 self._parent._elements[self._start + index] = value
 
             def __delitem__(self, index: int) -> None:
+                index = TypeChecker.validate_index(index)
                 if not 0 <= index < self.size:
                     raise IndexError(f"Index {index} out of bounds for sublist of size {self.size}")
                 del self._parent._elements[self._start + index]
@@ -481,6 +670,7 @@ class KotMutableList(KotList[T]):
                 return True
 
             def add_at(self, index: int, element: T) -> None:
+                index = TypeChecker.validate_index(index)
                 if not 0 <= index <= self.size:
                     raise IndexError(f"Index {index} out of bounds for insertion in sublist of size {self.size}")
                 self._parent._check_type(element)
@@ -489,6 +679,7 @@ class KotMutableList(KotList[T]):
 
             def set(self, index: int, element: T) -> T:
                 """Set element at the specified index in the sublist."""
+                index = TypeChecker.validate_index(index)
                 if not 0 <= index < self.size:
                     raise IndexError(f"Index {index} out of bounds for sublist of size {self.size}")
                 self._parent._check_type(element)
@@ -497,6 +688,7 @@ class KotMutableList(KotList[T]):
                 return old_element
 
             def remove_at(self, index: int) -> T:
+                index = TypeChecker.validate_index(index)
                 if not 0 <= index < self.size:
                     raise IndexError(f"Index {index} out of bounds for sublist of size {self.size}")
                 element = self._parent._elements.pop(self._start + index)
@@ -4,13 +4,16 @@ KotMutableMap: A Python implementation of Kotlin's MutableMap interface with sna
 
 from __future__ import annotations
 
-from typing import TypeVar, Dict, List, Iterator, Optional, Callable, Tuple, Type
+from typing import TypeVar, Dict, Iterable, List, Iterator, Optional, Callable, Tuple, Type
 
 from kotcollections.kot_map import KotMap
+from kotcollections.type_checker import TypeChecker
 
 K = TypeVar('K')
 V = TypeVar('V')
 
+# Sentinel distinguishing an absent key from a key mapped to None in single-lookup operations
+_MISSING = object()
 
 
 class KotMutableMap(KotMap[K, V]):
@@ -20,13 +23,18 @@ class KotMutableMap(KotMap[K, V]):
     Kotlin MutableMap functionality with snake_case naming.
     """
 
-    def __init__(self, elements: Optional[Dict[K, V] | List[Tuple[K, V]] | Iterator[Tuple[K, V]]] = None):
+    def __init__(
+        self,
+        elements: Optional[Dict[K, V] | List[Tuple[K, V]] | Iterator[Tuple[K, V]]] = None,
+        strict: bool = False
+    ):
         """Initialize a KotMutableMap with optional elements.
-        
+
         Args:
             elements: Initial elements for the map (dict, list of tuples, or iterator of tuples)
+            strict: If True, raise ValueError on duplicate keys instead of keeping the last value
         """
-        super().__init__(elements)
+        super().__init__(elements, strict)
 
     @classmethod
     def __class_getitem__(cls, types: Tuple[Type[K], Type[V]]) -> Type['KotMutableMap[K, V]']:
@@ -40,22 +48,15 @@ class KotMutableMap(KotMap[K, V]):
         key_type, value_type = types
         
         class TypedKotMutableMap(cls):
-            def __init__(self, elements=None):
+            def __init__(self, elements=None, strict=False):
                 # Only set types if they are actual types, not type variables
                 self._elements = {}
                 self._key_type = key_type if isinstance(key_type, type) else None
                 self._value_type = value_type if isinstance(value_type, type) else None
+                self._key_type_declared = self._key_type is not None
+                self._value_type_declared = self._value_type is not None
                 # Now process elements with the correct types set
-                if elements is not None:
-                    if isinstance(elements, dict):
-                        for key, value in elements.items():
-                            self._put_with_type_check(key, value)
-                    elif isinstance(elements, list):
-                        for key, value in elements:
-                            self._put_with_type_check(key, value)
-                    else:  # Iterator
-                        for key, value in elements:
-                            self._put_with_type_check(key, value)
+                self._init_elements(elements, strict)
         
         # Set a meaningful name for debugging (handle cases where __name__ might not exist)
         key_type_name = getattr(key_type, '__name__', str(key_type))
@@ -101,10 +102,23 @@ class KotMutableMap(KotMap[K, V]):
             ...     [("Buddy", Dog("Buddy"))])
             >>> animals_by_name.put("Whiskers", Cat("Whiskers"))  # Type-checked at runtime
         """
+        TypeChecker.validate_declared_type(key_type, "KotMutableMap")
+        TypeChecker.validate_declared_type(value_type, "KotMutableMap")
         # Use __class_getitem__ to create the same dynamic subclass
         typed_class = cls[key_type, value_type]
         return typed_class(elements)
 
+    def _value_index(self) -> Optional[Dict[V, List[K]]]:
+        """Mutable maps do not cache a value index; value lookups always scan the entries."""
+        return None
+
+    def _reset_inferred_types(self) -> None:
+        """Forget key and value types inferred from removed entries; declared types are kept."""
+        if not self._key_type_declared:
+            self._key_type = None
+        if not self._value_type_declared:
+            self._value_type = None
+
     # Mutation operations
 
     def put(self, key: K, value: V) -> Optional[V]:
@@ -117,11 +131,11 @@ class KotMutableMap(KotMap[K, V]):
         self._put_with_type_check(key, value)
         return old_value
 
-    def put_all(self, from_map: Dict[K, V] | 'KotMap[K, V]' | List[Tuple[K, V]]) -> None:
-        """Updates this map with key/value pairs from the specified map."""
+    def put_all(self, from_map: Dict[K, V] | 'KotMap[K, V]' | Iterable[Tuple[K, V]]) -> None:
+        """Updates this map with key/value pairs from the specified map, dict or iterable of pairs."""
         if isinstance(from_map, KotMap):
             from_map = from_map._elements
-        elif isinstance(from_map, list):
+        elif not isinstance(from_map, dict):
             from_map = dict(from_map)
         
         for key, value in from_map.items():
@@ -147,8 +161,7 @@ class KotMutableMap(KotMap[K, V]):
         if key in self._elements:
             value = self._elements.pop(key)
             if self.is_empty():
-                self._key_type = None
-                self._value_type = None
+                self._reset_inferred_types()
             return value
         return None
 
@@ -166,8 +179,32 @@ class KotMutableMap(KotMap[K, V]):
     def clear(self) -> None:
         """Removes all key/value pairs from the map."""
         self._elements.clear()
-        self._key_type = None
-        self._value_type = None
+        self._reset_inferred_types()
+
+    def remove_keys(self, keys: Iterable[K]) -> int:
+        """Removes all entries whose keys are contained in the given collection.
+
+        Returns:
+            The number of entries removed.
+        """
+        removed = 0
+        for key in keys:
+            if key in self._elements:
+                del self._elements[key]
+                removed += 1
+        if self.is_empty():
+            self._reset_inferred_types()
+        return removed
+
+    def retain_keys(self, keys: Iterable[K]) -> int:
+        """Retains only the entries whose keys are contained in the given collection.
+
+        Returns:
+            The number of entries removed.
+        """
+        keep = set(keys)
+        to_remove = [key for key in self._elements if key not in keep]
+        return self.remove_keys(to_remove)
 
     # Advanced mutation operations
 
@@ -179,17 +216,35 @@ class KotMutableMap(KotMap[K, V]):
         self._put_with_type_check(key, value)
         return value
 
+    def get_or_put_nullable(self, key: K, default_value: Callable[[], Optional[V]]) -> Optional[V]:
+        """Like get_or_put(), but for defaults that may produce None.
+
+        A None result is stored as an explicit entry, so a later call finds the key present and
+        returns None without invoking default_value again. Useful for memoization where None is
+        a valid cached result.
+
+        Examples:
+            >>> cache = KotMutableMap()
+            >>> cache.get_or_put_nullable("missing", lambda: None)  # Returns None
+            >>> cache.contains_key("missing")  # Returns True
+        """
+        if key in self._elements:
+            return self._elements[key]
+        value = default_value()
+        self._put_with_type_check(key, value)
+        return value
+
     def compute(self, key: K, remapping_function: Callable[[K, Optional[V]], Optional[V]]) -> Optional[V]:
         """Attempts to compute a mapping for the specified key and its current mapped value.
         
         The remapping function receives the key and current value (or null if not present).
         If it returns null, the mapping is removed (if it was present).
         """
-        current_value = self._elements.get(key)
-        new_value = remapping_function(key, current_value)
-        
+        current_value = self._elements.get(key, _MISSING)
+        new_value = remapping_function(key, None if current_value is _MISSING else current_value)
+
         if new_value is None:
-            if key in self._elements:
+            if current_value is not _MISSING:
                 self.remove(key)
         else:
             self._put_with_type_check(key, new_value)
@@ -202,9 +257,10 @@ class KotMutableMap(KotMap[K, V]):
         Returns:
             The current (existing or computed) value associated with the specified key.
         """
-        if key in self._elements:
-            return self._elements[key]
-        
+        current_value = self._elements.get(key, _MISSING)
+        if current_value is not _MISSING:
+            return current_value
+
         value = mapping_function(key)
         if value is not None:
             self._put_with_type_check(key, value)
@@ -216,10 +272,10 @@ class KotMutableMap(KotMap[K, V]):
         The remapping function receives the key and current value.
         If it returns null, the mapping is removed.
         """
-        if key not in self._elements:
+        current_value = self._elements.get(key, _MISSING)
+        if current_value is _MISSING:
             return None
-        
-        current_value = self._elements[key]
+
         new_value = remapping_function(key, current_value)
         
         if new_value is None:
@@ -246,6 +302,13 @@ class KotMutableMap(KotMap[K, V]):
             new_value = transform(key, current_value)
             self._put_with_type_check(key, new_value)
 
+    def transform_values_in_place(self, transform: Callable[[V], V]) -> None:
+        """Replaces each value with the result of invoking the given function on that value.
+
+        Equivalent to replace_all(lambda k, v: transform(v)). Values are checked against value_type if it is set.
+        """
+        self.replace_all(lambda _, value: transform(value))
+
     def merge(self, key: K, value: V, remapping_function: Callable[[V, V], V]) -> V:
         """If the specified key is not already associated with a value or is associated with null, associates it with the given value.
         Otherwise, replaces the value with the results of the given remapping function.
@@ -253,11 +316,11 @@ class KotMutableMap(KotMap[K, V]):
         Returns:
             The new value associated with the specified key.
         """
-        if key not in self._elements:
+        old_value = self._elements.get(key, _MISSING)
+        if old_value is _MISSING:
             self._put_with_type_check(key, value)
             return value
-        
-        old_value = self._elements[key]
+
         new_value = remapping_function(old_value, value)
         self._put_with_type_check(key, new_value)
         return new_value
@@ -309,5 +372,13 @@ class KotMutableMap(KotMap[K, V]):
         """Update the map with key/value pairs from other, overwriting existing keys."""
         self.put_all(other)
 
+    def snapshot(self) -> KotMap[K, V]:
+        """Returns an immutable copy of the current entries, safe to iterate while this map is mutated."""
+        return self.to_kot_map()
+
+    def __repr__(self) -> str:
+        """Return string representation of the mutable map."""
+        return f"KotMutableMap{self._declared_types_suffix()}({dict(self._elements)})"
+
     # Override __hash__ to make it unhashable (mutable objects shouldn't be hashable)
     __hash__ = None  # type: ignore
\ No newline at end of file
@@ -7,6 +7,7 @@ from __future__ import annotations
 from typing import TypeVar, Set, List, Iterator, Optional, Callable, Type, TYPE_CHECKING, Dict, Tuple
 
 from kotcollections.kot_set import KotSet
+from kotcollections.type_checker import TypeChecker
 
 if TYPE_CHECKING:
     from kotcollections.kot_list import KotList
@@ -15,7 +16,6 @@ if TYPE_CHECKING:
 T = TypeVar('T')
 
 
-
 class KotMutableSet(KotSet[T]):
     """A Python implementation of Kotlin's MutableSet interface.
     
@@ -47,6 +47,7 @@ class KotMutableSet(KotSet[T]):
                     self._element_type = element_type
                 else:
                     self._element_type = None
+                self._type_declared = self._element_type is not None
                 self._elements = set()
                 # Now process elements with the correct type set
                 if elements is not None:
@@ -104,10 +105,16 @@ class KotMutableSet(KotSet[T]):
             >>> animals = KotMutableSet.of_type(Animal, [Dog("Buddy")])
             >>> animals.add(Cat("Whiskers"))  # Type-checked at runtime
         """
+        TypeChecker.validate_declared_type(element_type, "KotMutableSet")
         # Use __class_getitem__ to create the same dynamic subclass
         typed_class = cls[element_type]
         return typed_class(elements)
 
+    def _reset_inferred_type(self) -> None:
+        """Forget an element type inferred from removed elements; a declared type is kept."""
+        if not self._type_declared:
+            self._element_type = None
+
     # Mutation operations
 
     def add(self, element: T) -> bool:
@@ -150,7 +157,7 @@ class KotMutableSet(KotSet[T]):
         if element in self._elements:
             self._elements.remove(element)
             if self.is_empty():
-                self._element_type = None
+                self._reset_inferred_type()
             return True
         return False
 
@@ -171,7 +178,7 @@ class KotMutableSet(KotSet[T]):
         initial_size = self.size
         self._elements.difference_update(elements)
         if self.is_empty():
-            self._element_type = None
+            self._reset_inferred_type()
         return self.size < initial_size
 
     def retain_all(self, elements: Set[T] | List[T] | 'KotSet[T]' | 'KotList[T]' | 'KotMutableList[T]') -> bool:
@@ -191,13 +198,26 @@ class KotMutableSet(KotSet[T]):
         initial_size = self.size
         self._elements.intersection_update(elements)
         if self.is_empty():
-            self._element_type = None
+            self._reset_inferred_type()
         return self.size < initial_size
 
     def clear(self) -> None:
         """Removes all elements from this set."""
         self._elements.clear()
-        self._element_type = None
+        self._reset_inferred_type()
+
+    def drain(self) -> KotSet[T]:
+        """Removes all elements and returns them as a KotSet, preserving the element type.
+
+        A declared element type stays in force on this set after it has been drained.
+
+        Examples:
+            >>> pending = KotMutableSet([1, 2])
+            >>> pending.drain()  # Returns KotSet({1, 2}); pending is now empty
+        """
+        drained = self.to_kot_set()
+        self.clear()
+        return drained
 
     # Additional mutation operations
 
@@ -212,7 +232,7 @@ class KotMutableSet(KotSet[T]):
             for element in to_remove:
                 self._elements.remove(element)
             if self.is_empty():
-                self._element_type = None
+                self._reset_inferred_type()
             return True
         return False
 
@@ -227,7 +247,7 @@ class KotMutableSet(KotSet[T]):
             for element in to_remove:
                 self._elements.remove(element)
             if self.is_empty():
-                self._element_type = None
+                self._reset_inferred_type()
             return True
         return False
 
@@ -253,7 +273,7 @@ class KotMutableSet(KotSet[T]):
             other = set(other)
         self._elements.intersection_update(other)
         if self.is_empty():
-            self._element_type = None
+            self._reset_inferred_type()
 
     def subtract_update(self, other: Set[T] | 'KotSet[T]' | 'KotList[T]' | 'KotMutableList[T]') -> None:
         """Removes all elements that are contained in the specified collection."""
@@ -264,7 +284,7 @@ class KotMutableSet(KotSet[T]):
             other = set(other)
         self._elements.difference_update(other)
         if self.is_empty():
-            self._element_type = None
+            self._reset_inferred_type()
 
     # Operator overloads for mutation
 
@@ -283,6 +303,10 @@ class KotMutableSet(KotSet[T]):
         self.intersect_update(other)
         return self
 
+    def snapshot(self) -> KotSet[T]:
+        """Returns an immutable copy of the current elements, safe to iterate while this set is mutated."""
+        return self.to_kot_set()
+
     def __repr__(self) -> str:
         """Return string representation of the mutable set."""
-        return f"KotMutableSet({list(self._elements)})"
+        return f"KotMutableSet{self._declared_type_suffix()}({list(self._elements)})"
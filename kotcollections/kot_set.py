@@ -4,9 +4,12 @@ KotSet: A Python implementation of Kotlin's Set interface with snake_case naming
 
 from __future__ import annotations
 
-from collections import defaultdict
+import io
+import math
+import random as _random
+from collections import Counter, defaultdict
 from functools import reduce
-from typing import TypeVar, Generic, Callable, Optional, Set, Iterator, Any, Tuple, List, Type, TYPE_CHECKING, Dict
+from typing import TypeVar, Generic, Callable, Optional, Set, Iterator, Any, Tuple, List, Type, TYPE_CHECKING, Dict, FrozenSet, Iterable, Union
 
 from kotcollections.type_checker import TypeChecker
 
@@ -18,7 +21,9 @@ if TYPE_CHECKING:
 
 T = TypeVar('T')
 R = TypeVar('R')
-
+K = TypeVar('K')
+V = TypeVar('V')
+W = TypeVar('W')
 
 
 class KotSet(Generic[T]):
@@ -28,6 +33,9 @@ class KotSet(Generic[T]):
     maintaining type safety and immutability.
     """
 
+    # True when the element type was declared via of_type() or KotSet[T], not inferred from an element
+    _type_declared: bool = False
+
     def __init__(self, elements: Optional[Set[T] | List[T] | Iterator[T] | 'KotList[T]' | 'KotMutableList[T]'] = None):
         """Initialize a KotSet with optional elements.
         
@@ -69,6 +77,7 @@ class KotSet(Generic[T]):
                     self._element_type = element_type
                 else:
                     self._element_type = None
+                self._type_declared = self._element_type is not None
                 self._elements = set()
                 # Now process elements with the correct type set
                 if elements is not None:
@@ -128,10 +137,60 @@ class KotSet(Generic[T]):
             >>> # Equivalent to __class_getitem__ syntax
             >>> animals = KotSet[Animal]([Dog("Buddy"), Cat("Whiskers")])
         """
+        TypeChecker.validate_declared_type(element_type, "KotSet")
         # Use __class_getitem__ to create the same dynamic subclass
         typed_class = cls[element_type]
         return typed_class(elements)
 
+    @classmethod
+    def empty_of_type(cls, element_type: Type[T]) -> 'KotSet[T]':
+        """Create an empty KotSet whose element type is fixed up front.
+
+        Unlike an empty ``KotSet()``, which infers its type from the first element,
+        the returned set type-checks every element against ``element_type``.
+        """
+        return cls.of_type(element_type)
+
+    @classmethod
+    def or_empty(cls, value: Optional[Iterable[T]]) -> 'KotSet[T]':
+        """Returns an empty KotSet if value is None, like Kotlin's orEmpty().
+
+        A KotSet is returned as is; any other value is passed to the constructor.
+
+        Examples:
+            >>> KotSet.or_empty(None)  # Returns an empty set
+        """
+        if value is None:
+            return cls()
+        if isinstance(value, cls):
+            return value
+        return cls(value)
+
+    @classmethod
+    def union_all(cls, collections: Iterable[Iterable[T]]) -> 'KotSet[T]':
+        """Combine many collections or iterables into one set of their distinct elements in a single pass.
+
+        The element type is kept when every Kot collection source has the same element type.
+
+        Examples:
+            >>> KotSet.union_all([KotSet([1, 2]), [2, 3], (3, 4)])  # Returns KotSet([1, 2, 3, 4])
+        """
+        from kotcollections.kot_list import _concat_sources
+        elements, element_type, declared = _concat_sources(collections)
+        return cls._of_element_type(element_type, elements, declared)
+
+    @classmethod
+    def _of_element_type(cls, element_type: Optional[type], elements: Iterable[T], type_declared: bool) -> 'KotSet[T]':
+        """Create a KotSet of the given elements with element_type, recording whether that type was declared.
+
+        An element_type of None creates an untyped KotSet that infers its type from the elements.
+        """
+        if element_type is None:
+            return cls(elements)
+        result = cls.of_type(element_type, elements)
+        result._type_declared = type_declared
+        return result
+
     def _add_with_type_check(self, element: T) -> None:
         """Add an element with type checking.
 
@@ -183,6 +242,19 @@ class KotSet(Generic[T]):
             elements = set(elements)
         return elements.issubset(self._elements)
 
+    def contains_any(self, elements: Iterable[T]) -> bool:
+        """Returns true if at least one of the given elements is in this set, stopping at the first hit.
+
+        Unhashable elements can never be members, so they are skipped.
+        """
+        for element in elements:
+            try:
+                if element in self._elements:
+                    return True
+            except TypeError:
+                continue
+        return False
+
     # Access operations
 
     def first(self) -> T:
@@ -219,6 +291,56 @@ class KotSet(Generic[T]):
         """Pythonic alias for first_or_null_predicate()."""
         return self.first_or_null_predicate(predicate)
 
+    def first_is_instance(self, klass: Type[R]) -> R:
+        """Returns the first element (in iteration order) that is an instance of klass.
+
+        Raises:
+            ValueError: If no element is an instance of klass.
+        """
+        for element in self._elements:
+            if isinstance(element, klass):
+                return element
+        raise ValueError(f"No element of type '{klass.__name__}' found")
+
+    def first_is_instance_or_null(self, klass: Type[R]) -> Optional[R]:
+        """Returns the first element (in iteration order) that is an instance of klass, or null if there is none."""
+        for element in self._elements:
+            if isinstance(element, klass):
+                return element
+        return None
+
+    def first_is_instance_or_none(self, klass: Type[R]) -> Optional[R]:
+        """Pythonic alias for first_is_instance_or_null()."""
+        return self.first_is_instance_or_null(klass)
+
+    def last_is_instance(self, klass: Type[R]) -> R:
+        """Returns the last element (in iteration order) that is an instance of klass.
+
+        Raises:
+            ValueError: If no element is an instance of klass.
+        """
+        result = None
+        found = False
+        for element in self._elements:
+            if isinstance(element, klass):
+                result = element
+                found = True
+        if not found:
+            raise ValueError(f"No element of type '{klass.__name__}' found")
+        return result
+
+    def last_is_instance_or_null(self, klass: Type[R]) -> Optional[R]:
+        """Returns the last element (in iteration order) that is an instance of klass, or null if there is none."""
+        result = None
+        for element in self._elements:
+            if isinstance(element, klass):
+                result = element
+        return result
+
+    def last_is_instance_or_none(self, klass: Type[R]) -> Optional[R]:
+        """Pythonic alias for last_is_instance_or_null()."""
+        return self.last_is_instance_or_null(klass)
+
     def last(self) -> T:
         """Returns the last element."""
         if self.is_empty():
@@ -273,6 +395,31 @@ class KotSet(Generic[T]):
         """Pythonic alias for single_or_null_predicate()."""
         return self.single_or_null_predicate(predicate)
 
+    def random_take(self, n: int, random_instance: Optional[_random.Random] = None) -> 'KotList[T]':
+        """Returns a list of n distinct randomly chosen elements from this set (sampling without replacement).
+
+        Raises:
+            ValueError: If n is negative or greater than the size of this set.
+        """
+        from kotcollections.kot_list import KotList
+        if n < 0:
+            raise ValueError("Requested element count is less than zero")
+        if n > self.size:
+            raise ValueError(f"Requested element count {n} is greater than set size {self.size}")
+        if random_instance:
+            return KotList(random_instance.sample(list(self._elements), n))
+        return KotList(_random.sample(list(self._elements), n))
+
+    def random_take_or_null(self, n: int, random_instance: Optional[_random.Random] = None) -> Optional['KotList[T]']:
+        """Returns a list of n distinct randomly chosen elements from this set, or null if n is out of range."""
+        if not 0 <= n <= self.size:
+            return None
+        return self.random_take(n, random_instance)
+
+    def random_take_or_none(self, n: int, random_instance: Optional[_random.Random] = None) -> Optional['KotList[T]']:
+        """Pythonic alias for random_take_or_null()."""
+        return self.random_take_or_null(n, random_instance)
+
     # Transformation operations
 
     def map(self, transform: Callable[[T], R]) -> 'KotList[R]':
@@ -387,6 +534,51 @@ class KotSet(Generic[T]):
             return self.size
         return sum(1 for element in self._elements if predicate(element))
 
+    def count_distinct(self) -> int:
+        """Returns the number of distinct elements, which for a set is its size."""
+        return self.size
+
+    def count_distinct_by(self, selector: Callable[[T], R]) -> int:
+        """Returns the number of distinct keys returned by the given selector."""
+        return len({selector(element) for element in self._elements})
+
+    def count_by(self, key_selector: Optional[Callable[[T], R]] = None) -> 'KotMap[Any, int]':
+        """Returns a KotMap from each key (or each element if no selector is given) to the number of elements with it.
+
+        Examples:
+            >>> KotSet(["apple", "avocado", "banana"]).count_by(lambda s: s[0])  # Returns KotMap({'a': 2, 'b': 1})
+        """
+        from kotcollections.kot_map import KotMap
+        counts: Dict[Any, int] = {}
+        for element in self._elements:
+            key = key_selector(element) if key_selector is not None else element
+            TypeChecker.validate_hashable(key, "count_by key")
+            counts[key] = counts.get(key, 0) + 1
+        if key_selector is None and self._element_type is not None:
+            return KotMap._of_types(self._element_type, int, counts, self._type_declared, False)
+        return KotMap(counts)
+
+    def most_common(self, n: Optional[int] = None) -> 'KotList[Tuple[T, int]]':
+        """Returns (element, count) pairs sorted by descending count, like collections.Counter.most_common.
+
+        Ties keep the order of first appearance. At most n pairs are returned when n is given.
+
+        Raises:
+            ValueError: If n is negative
+        """
+        from kotcollections.kot_list import KotList
+        if n is not None and n < 0:
+            raise ValueError("Requested element count is less than zero")
+        entries = sorted(self.count_by().to_dict().items(), key=lambda entry: -entry[1])
+        return KotList(entries if n is None else entries[:n])
+
+    def sum(self) -> Any:
+        """Returns the sum of all elements, or 0 for an empty set.
+
+        Elements are added with Python's own addition, so Decimal and Fraction sums stay exact.
+        """
+        return sum(self._elements)
+
     def sum_of(self, selector: Callable[[T], float | int]) -> float | int:
         """Returns the sum of all values produced by selector function."""
         if self.is_empty():
@@ -401,6 +593,29 @@ class KotSet(Generic[T]):
         values = [selector(element) for element in self._elements]
         return sum(values) / len(values)
 
+    def average_or_null(self, selector: Callable[[T], float | int]) -> Optional[float]:
+        """Returns the average of all values produced by selector function, or null if the set is empty."""
+        if self.is_empty():
+            return None
+        return self.average(selector)
+
+    def average_or_none(self, selector: Callable[[T], float | int]) -> Optional[float]:
+        """Pythonic alias for average_or_null()."""
+        return self.average_or_null(selector)
+
+    def variance(self, sample: bool = False) -> float:
+        """Returns the population variance of the elements, or the sample variance if sample is True.
+
+        Raises:
+            ValueError: If the set is empty, or has fewer than two elements for the sample variance
+        """
+        from kotcollections.kot_list import _variance
+        return _variance(list(self._elements), sample, "Set")
+
+    def standard_deviation(self, sample: bool = False) -> float:
+        """Returns the population standard deviation, or the sample standard deviation if sample is True."""
+        return math.sqrt(self.variance(sample))
+
     def max_or_null(self) -> Optional[T]:
         """Returns the largest element or null if there are no elements."""
         if self.is_empty():
@@ -421,25 +636,43 @@ class KotSet(Generic[T]):
         """Pythonic alias for min_or_null()."""
         return self.min_or_null()
 
-    def max_by_or_null(self, selector: Callable[[T], Any]) -> Optional[T]:
+    @staticmethod
+    def _extreme_key(
+        selector: Callable[[T], Any],
+        tie_breaker: Optional[Callable[[T], Any]]
+    ) -> Callable[[T], Any]:
+        """Build the key for max_by/min_by, ordering equal selector values by tie_breaker if given."""
+        if tie_breaker is None:
+            return selector
+        return lambda element: (selector(element), tie_breaker(element))
+
+    def max_by_or_null(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> Optional[T]:
         """Returns the element with the largest value of the selector function."""
         if self.is_empty():
             return None
-        return max(self._elements, key=selector)
+        return max(self._elements, key=self._extreme_key(selector, tie_breaker))
 
-    def max_by_or_none(self, selector: Callable[[T], Any]) -> Optional[T]:
+    def max_by_or_none(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> Optional[T]:
         """Pythonic alias for max_by_or_null()."""
-        return self.max_by_or_null(selector)
+        return self.max_by_or_null(selector, tie_breaker)
 
-    def min_by_or_null(self, selector: Callable[[T], Any]) -> Optional[T]:
+    def min_by_or_null(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> Optional[T]:
         """Returns the element with the smallest value of the selector function."""
         if self.is_empty():
             return None
-        return min(self._elements, key=selector)
+        return min(self._elements, key=self._extreme_key(selector, tie_breaker))
 
-    def min_by_or_none(self, selector: Callable[[T], Any]) -> Optional[T]:
+    def min_by_or_none(
+        self, selector: Callable[[T], Any], tie_breaker: Optional[Callable[[T], Any]] = None
+    ) -> Optional[T]:
         """Pythonic alias for min_by_or_null()."""
-        return self.min_by_or_null(selector)
+        return self.min_by_or_null(selector, tie_breaker)
 
     # Collection operations
 
@@ -463,6 +696,12 @@ class KotSet(Generic[T]):
         """Pythonic alias for reduce_or_null()."""
         return self.reduce_or_null(operation)
 
+    def reduce_or_else(self, operation: Callable[[T, T], T], default_value: Callable[[], T]) -> T:
+        """Accumulates value starting with the first element, or returns the result of default_value() if empty."""
+        if self.is_empty():
+            return default_value()
+        return reduce(operation, self._elements)
+
     def group_by(self, key_selector: Callable[[T], R]) -> 'KotMap[R, KotList[T]]':
         """Groups elements by the key returned by the given key_selector function."""
         from kotcollections.kot_map import KotMap
@@ -470,6 +709,7 @@ class KotSet(Generic[T]):
         groups = defaultdict(list)
         for element in self._elements:
             key = key_selector(element)
+            TypeChecker.validate_hashable(key, "group_by key")
             groups[key].append(element)
         return KotMap({key: KotList(elements) for key, elements in groups.items()})
 
@@ -484,6 +724,7 @@ class KotSet(Generic[T]):
         groups = defaultdict(list)
         for element in self._elements:
             key = key_selector(element)
+            TypeChecker.validate_hashable(key, "group_by_to key")
             value = value_transform(element)
             groups[key].append(value)
         return KotMap({key: KotList(values) for key, values in groups.items()})
@@ -499,9 +740,13 @@ class KotSet(Generic[T]):
         return KotMap({key_selector(element): element for element in self._elements})
 
     def associate_with(self, value_selector: Callable[[T], R]) -> 'KotMap[T, R]':
-        """Returns a Map where keys are elements and values are produced by value_selector."""
+        """Returns a Map where keys are elements and values are produced by value_selector.
+
+        The map's key type is this set's element type.
+        """
         from kotcollections.kot_map import KotMap
-        return KotMap({element: value_selector(element) for element in self._elements})
+        result = {element: value_selector(element) for element in self._elements}
+        return KotMap._of_types(self._element_type, None, result, self._type_declared, False)
 
     # Additional convenience operations
 
@@ -537,9 +782,29 @@ class KotSet(Generic[T]):
         for index, element in enumerate(self._elements):
             action(index, element)
 
-    def with_index(self) -> Iterator[Tuple[int, T]]:
-        """Returns an Iterator of IndexedValue for each element of the original set."""
-        return enumerate(self._elements)
+    def with_index(self) -> 'KotList[Tuple[int, T]]':
+        """Returns a KotList of (index, element) pairs for each element of the original set."""
+        from kotcollections.kot_list import KotList
+        return KotList(enumerate(self._elements))
+
+    def if_empty(self, default_value: Union[Callable[[], R], R]) -> Union['KotSet[T]', R]:
+        """Returns this set if it's not empty, otherwise the default value.
+
+        default_value may be a function producing the replacement (called only when the set is empty)
+        or the replacement value itself, e.g. if_empty(lambda: KotSet[T](...)) or if_empty(KotSet[T](...)).
+        """
+        if self.is_not_empty():
+            return self
+        return default_value() if callable(default_value) else default_value
+
+    def if_not_empty(self, default_value: Union[Callable[[], R], R]) -> Union['KotSet[T]', R]:
+        """Returns this set if it's empty, otherwise the default value - the mirror of if_empty().
+
+        Like if_empty(), default_value may be a function or the replacement value itself.
+        """
+        if self.is_empty():
+            return self
+        return default_value() if callable(default_value) else default_value
 
     def zip(self, other: Set[R] | List[R] | 'KotSet[R]' | 'KotList[R]' | 'KotMutableList[R]') -> 'KotSet[Tuple[T, R]]':
         """Returns a set of pairs built from the elements of this set and other collection with the same index."""
@@ -623,46 +888,92 @@ class KotSet(Generic[T]):
         """Returns a Python set containing all elements."""
         return set(self._elements.copy())
 
+    def to_frozenset(self) -> FrozenSet[T]:
+        """Returns a Python frozenset containing all elements."""
+        return frozenset(self._elements)
+
+    def to_counter(self, key_selector: Optional[Callable[[T], R]] = None) -> Counter:
+        """Returns a collections.Counter of the elements, or of the keys produced by key_selector.
+
+        Raises:
+            TypeError: If an element or key is not hashable
+        """
+        counter: Counter = Counter()
+        for element in self._elements:
+            key = key_selector(element) if key_selector is not None else element
+            TypeChecker.validate_hashable(key, "to_counter key")
+            counter[key] += 1
+        return counter
+
     def to_kot_list(self) -> 'KotList[T]':
         """Returns a Python list containing all elements."""
         from kotcollections.kot_list import KotList
         # Preserve type information when converting
-        if self._element_type is not None:
-            return KotList.of_type(self._element_type, list(self._elements))
-        else:
-            return KotList(self._elements.copy())
+        return KotList._of_element_type(self._element_type, list(self._elements), self._type_declared)
 
     def to_kot_mutable_list(self) -> 'KotMutableList[T]':
         """Returns a Python list containing all elements."""
         from kotcollections.kot_mutable_list import KotMutableList
         # Preserve type information when converting
-        if self._element_type is not None:
-            return KotMutableList.of_type(self._element_type, list(self._elements))
-        else:
-            return KotMutableList(self._elements.copy())
+        return KotMutableList._of_element_type(self._element_type, list(self._elements), self._type_declared)
 
     def to_kot_set(self) -> KotSet[T]:
         """Returns a Python set containing all elements."""
         # Preserve type information when converting
-        if self._element_type is not None:
-            return KotSet.of_type(self._element_type, self._elements.copy())
-        else:
-            return KotSet(self._elements.copy())
+        return KotSet._of_element_type(self._element_type, self._elements.copy(), self._type_declared)
 
     def to_kot_mutable_set(self) -> 'KotMutableSet[T]':
         """Returns a KotMutableSet containing all elements."""
         from kotcollections.kot_mutable_set import KotMutableSet
         # Preserve type information when converting
-        if self._element_type is not None:
-            mutable_set = KotMutableSet.of_type(self._element_type, self._elements.copy())
-        else:
-            mutable_set = KotMutableSet(self._elements.copy())
-        return mutable_set
+        return KotMutableSet._of_element_type(self._element_type, self._elements.copy(), self._type_declared)
+
+    def to_kot_map(self) -> 'KotMap[Any, Any]':
+        """Returns a KotMap built from this set of (key, value) pairs.
+
+        Raises:
+            ValueError: If an element is not a 2-element tuple.
+        """
+        return self.to_kot_list().to_kot_map()
+
+    def to_kot_map_of_types(self, key_type: Type[K], value_type: Type[V]) -> 'KotMap[K, V]':
+        """Returns a KotMap with the given key and value types built from this set of (key, value) pairs."""
+        return self.to_kot_list().to_kot_map_of_types(key_type, value_type)
 
     def to_sorted_set(self, key: Optional[Callable[[T], Any]] = None, reverse: bool = False) -> KotSet[T]:
         """Returns a sorted list of all elements."""
         return KotSet(sorted(self._elements, key=key, reverse=reverse))
 
+    def to_sorted_list(self, comparator: Optional[Callable[[T, T], int]] = None) -> 'KotList[T]':
+        """Returns a KotList of all elements sorted by natural order, or by the given comparator.
+
+        Without a comparator the elements must be mutually comparable.
+        """
+        from kotcollections.kot_list import KotList, _checked_comparator
+        key = _checked_comparator(comparator) if comparator is not None else None
+        elements = sorted(self._elements, key=key)
+        return KotList._of_element_type(self._element_type, elements, self._type_declared)
+
+    def join_to(
+        self,
+        destination: W,
+        separator: str = ", ",
+        prefix: str = "",
+        postfix: str = "",
+        limit: int = -1,
+        truncated: str = "...",
+        transform: Optional[Callable[[T], str]] = None
+    ) -> W:
+        """Appends the string from all the elements separated using separator to the given destination.
+
+        The destination is any object with a write(str) method, such as io.StringIO or an open text file.
+
+        Returns:
+            The destination.
+        """
+        from kotcollections.kot_list import _join_to
+        return _join_to(self._elements, destination, separator, prefix, postfix, limit, truncated, transform)
+
     def join_to_string(
         self,
         separator: str = ", ",
@@ -673,25 +984,29 @@ class KotSet(Generic[T]):
         transform: Optional[Callable[[T], str]] = None
     ) -> str:
         """Creates a string from all the elements separated using separator."""
-        elements = list(self._elements)
-        if 0 <= limit < len(elements):
-            elements = elements[:limit]
-            truncated_part = truncated
-        else:
-            truncated_part = ""
+        return self.join_to(io.StringIO(), separator, prefix, postfix, limit, truncated, transform).getvalue()
 
-        if transform:
-            elements_str = [transform(e) for e in elements]
-        else:
-            elements_str = [str(e) for e in elements]
+    # Python special methods
 
-        return prefix + separator.join(elements_str) + truncated_part + postfix
+    def is_type_declared(self) -> bool:
+        """Returns true if the element type was declared via of_type() or KotSet[T] rather than inferred."""
+        return self._type_declared and self._element_type is not None
 
-    # Python special methods
+    def _declared_type_suffix(self) -> str:
+        """Return "[type]" for use in repr when the element type was declared, or "" otherwise."""
+        if self.is_type_declared():
+            return f"[{self._element_type.__name__}]"
+        return ""
 
     def __repr__(self) -> str:
-        """Return string representation of the set."""
-        return f"KotSet({list(self._elements)})"
+        """Return string representation of the set, including the element type if it was declared."""
+        return f"KotSet{self._declared_type_suffix()}({list(self._elements)})"
+
+    def __format__(self, format_spec: str) -> str:
+        """Format the set, using a non-empty format spec as the join separator (e.g. f"{s:, }")."""
+        if not format_spec:
+            return str(self)
+        return self.join_to_string(separator=format_spec)
 
     def __iter__(self) -> Iterator[T]:
         """Return an iterator over the elements."""
@@ -705,6 +1020,19 @@ class KotSet(Generic[T]):
         """Return the size of the set."""
         return len(self._elements)
 
+    def equals_native(self, other: Any) -> bool:
+        """Returns true if this set is structurally equal to other once all Kot collections are unwrapped.
+
+        Nested KotList, KotSet and KotMap values compare equal to native lists, sets and dicts with the same
+        contents, which makes assertions against plain Python literals straightforward. Unlike ==, the
+        comparison ignores the Kot wrapping entirely.
+
+        Examples:
+            >>> KotSet([1, 2]).equals_native({1, 2})  # True
+        """
+        from kotcollections.native_equality import native_equals
+        return native_equals(self, other)
+
     def __eq__(self, other: object) -> bool:
         """Check if two sets are equal."""
         if not isinstance(other, KotSet):
@@ -714,3 +1042,30 @@ class KotSet(Generic[T]):
     def __hash__(self) -> int:
         """Return hash of the set."""
         return hash(frozenset(self._elements))
+
+    def __lt__(self, other: object) -> bool:
+        """Compare sets by their sorted element lists, lexicographically.
+
+        This canonical ordering requires the elements of both sets to be mutually comparable.
+        """
+        if not isinstance(other, KotSet):
+            return NotImplemented
+        return sorted(self._elements) < sorted(other._elements)
+
+    def __le__(self, other: object) -> bool:
+        """Check if this set is less than or equal to another set, comparing sorted element lists as in __lt__()."""
+        if not isinstance(other, KotSet):
+            return NotImplemented
+        return sorted(self._elements) <= sorted(other._elements)
+
+    def __gt__(self, other: object) -> bool:
+        """Check if this set is greater than another set, comparing sorted element lists as in __lt__()."""
+        if not isinstance(other, KotSet):
+            return NotImplemented
+        return sorted(self._elements) > sorted(other._elements)
+
+    def __ge__(self, other: object) -> bool:
+        """Check if this set is greater than or equal to another set, comparing sorted element lists as in __lt__()."""
+        if not isinstance(other, KotSet):
+            return NotImplemented
+        return sorted(self._elements) >= sorted(other._elements)
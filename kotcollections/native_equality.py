@@ -0,0 +1,61 @@
+"""
+Native equality: deep comparison of Kot collections against plain Python structures.
+
+This module provides the shared logic behind equals_native() on KotList, KotSet,
+KotMap and their mutable variations.
+"""
+
+from typing import Any
+
+
+def unwrap(value: Any) -> Any:
+    """Convert a Kot collection to its native counterpart, one level deep.
+
+    KotList becomes a list, KotSet a set and KotMap a dict; nested values are left as they are
+    and any other value is returned unchanged.
+    """
+    from kotcollections.kot_list import KotList
+    from kotcollections.kot_map import KotMap
+    from kotcollections.kot_set import KotSet
+
+    if isinstance(value, KotList):
+        return list(value._elements)
+    if isinstance(value, KotSet):
+        return set(value._elements)
+    if isinstance(value, KotMap):
+        return dict(value._elements)
+    return value
+
+
+def native_equals(left: Any, right: Any) -> bool:
+    """Compare two values structurally, unwrapping Kot collections at every level.
+
+    Lists and tuples compare element by element, dicts by keys and then values, and sets
+    by finding a structurally equal partner for every element. Other values use ==.
+    """
+    left = unwrap(left)
+    right = unwrap(right)
+
+    if isinstance(left, dict) and isinstance(right, dict):
+        return left.keys() == right.keys() and all(native_equals(left[k], right[k]) for k in left)
+    if isinstance(left, (list, tuple)) and isinstance(right, (list, tuple)):
+        return (
+            type(left) is type(right)
+            and len(left) == len(right)
+            and all(native_equals(a, b) for a, b in zip(left, right))
+        )
+    if isinstance(left, (set, frozenset)) and isinstance(right, (set, frozenset)):
+        if len(left) != len(right):
+            return False
+        unmatched = list(right)
+        for element in left:
+            for i, candidate in enumerate(unmatched):
+                if native_equals(element, candidate):
+                    del unmatched[i]
+                    break
+            else:
+                return False
+        return True
+    if isinstance(left, (dict, list, tuple, set, frozenset)) or isinstance(right, (dict, list, tuple, set, frozenset)):
+        return False
+    return left == right
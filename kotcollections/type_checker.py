@@ -5,6 +5,7 @@ This module provides shared type checking logic used across KotList, KotMap,
 KotSet and their mutable variations.
 """
 
+import operator
 from typing import Any, Optional, Type
 
 
@@ -102,6 +103,81 @@ class TypeChecker:
                 f"to {collection_name}[{expected_name}]"
             )
 
+    @staticmethod
+    def validate_declared_type(declared_type: Any, collection_name: str) -> None:
+        """Validate that a type passed to of_type() is an actual type.
+
+        __class_getitem__ tolerates type variables so generic annotations keep working, but a
+        non-type passed explicitly to of_type() would silently disable type checking.
+
+        Args:
+            declared_type: The type passed to of_type()
+            collection_name: Name of the collection for error messages
+
+        Raises:
+            TypeError: If declared_type is not a type
+        """
+        if not isinstance(declared_type, type):
+            raise TypeError(
+                f"{collection_name}.of_type() expects a type, "
+                f"got {type(declared_type).__name__} {declared_type!r}"
+            )
+
+    @staticmethod
+    def validate_index(index: Any) -> int:
+        """Validate that an index is an integer and return it as an int.
+
+        Accepts any object implementing __index__ (e.g. numpy integers), but rejects
+        floats, strings and other non-integral values with a clear message.
+
+        Args:
+            index: The index to validate
+
+        Returns:
+            The index as a plain int
+
+        Raises:
+            TypeError: If the index is not an integer
+        """
+        return TypeChecker.validate_integer(index, "Index")
+
+    @staticmethod
+    def validate_integer(value: Any, description: str) -> int:
+        """Validate that a value is an integer and return it as an int.
+
+        Args:
+            value: The value to validate
+            description: What the value is used as, for error messages (e.g. "Rotation distance")
+
+        Returns:
+            The value as a plain int
+
+        Raises:
+            TypeError: If the value is not an integer
+        """
+        try:
+            return operator.index(value)
+        except TypeError:
+            raise TypeError(f"{description} must be an integer, got '{type(value).__name__}'") from None
+
+    @staticmethod
+    def validate_hashable(value: Any, description: str) -> None:
+        """Validate that a value is hashable and raise TypeError with a clear message if not.
+
+        Args:
+            value: The value to validate
+            description: What the value is used as, for error messages (e.g. "group_by key")
+
+        Raises:
+            TypeError: If the value is unhashable
+        """
+        try:
+            hash(value)
+        except TypeError:
+            raise TypeError(
+                f"{description} must be hashable, got unhashable type '{type(value).__name__}'"
+            ) from None
+
     @staticmethod
     def should_skip_type_checking(expected_type: Optional[Type]) -> bool:
         """Check if type checking should be skipped.
@@ -1,6 +1,6 @@
 import unittest
 
-from kotcollections import KotList, KotMap, KotGrouping
+from kotcollections import KotList, KotMap, KotMutableMap, KotGrouping
 
 
 class TestKotGroupingBasics(unittest.TestCase):
@@ -214,3 +214,93 @@ class TestKotGroupingIntegration(unittest.TestCase):
         self.assertEqual(char_counts.get(3), 9)  # cat(3) + dog(3) + bee(3) = 9
         self.assertEqual(char_counts.get(4), 8)  # bird(4) + fish(4) = 8
         self.assertEqual(char_counts.get(8), 8)  # elephant(8) = 8
+
+
+class TestKotGroupingDestinations(unittest.TestCase):
+    def setUp(self):
+        self.grouping = KotList(["apple", "apricot", "banana", "cherry", "avocado"]).grouping_by(lambda s: s[0])
+
+    def test_each_count_to_dict(self):
+        """each_count_to accepts a plain dict and adds to existing counts"""
+        destination = {"a": 10}
+        result = self.grouping.each_count_to(destination)
+        self.assertIs(result, destination)
+        self.assertEqual(destination, {"a": 13, "b": 1, "c": 1})
+
+    def test_each_count_to_mutable_map(self):
+        """each_count_to accepts a KotMutableMap"""
+        destination = KotMutableMap()
+        self.grouping.each_count_to(destination)
+        self.assertEqual(destination.to_dict(), {"a": 3, "b": 1, "c": 1})
+
+    def test_fold_to(self):
+        """fold_to folds each group into the destination"""
+        destination = self.grouping.fold_to({}, lambda k, e: "", lambda k, acc, e: acc + e[1])
+        self.assertEqual(destination, {"a": "ppv", "b": "a", "c": "h"})
+        kot_destination = self.grouping.fold_to(KotMutableMap(), lambda k, e: 0, lambda k, acc, e: acc + len(e))
+        self.assertEqual(kot_destination.get("a"), 19)
+
+    def test_reduce_to(self):
+        """reduce_to reduces each group into the destination"""
+        destination = self.grouping.reduce_to({}, lambda k, acc, e: acc + "," + e)
+        self.assertEqual(destination["a"], "apple,apricot,avocado")
+        self.assertEqual(destination["b"], "banana")
+
+    def test_aggregate_to_matches_aggregate(self):
+        """aggregate_to into an empty destination matches aggregate()"""
+        op = lambda k, acc, e, first: e if first else acc + e
+        self.assertEqual(self.grouping.aggregate_to({}, op), self.grouping.aggregate(op).to_dict())
+
+
+class TestKotGroupingSourceAccess(unittest.TestCase):
+    def test_source_list(self):
+        """source_list returns the original elements in order"""
+        grouping = KotList(["apple", "banana", "avocado"]).grouping_by(lambda s: s[0])
+        source = grouping.source_list()
+        self.assertIsInstance(source, KotList)
+        self.assertEqual(source.to_list(), ["apple", "banana", "avocado"])
+        self.assertEqual(grouping.each_count().to_dict(), {"a": 2, "b": 1})
+
+    def test_key_of(self):
+        """key_of applies the grouping's key selector"""
+        grouping = KotList([1, 2, 3]).grouping_by(lambda x: x % 2)
+        self.assertEqual(grouping.key_of(5), 1)
+        self.assertEqual(grouping.key_of(4), 0)
+
+
+class TestKotGroupingFoldWithCount(unittest.TestCase):
+    def test_fold_with_count(self):
+        """fold_with_count returns each group's folded value together with its size"""
+        grouping = KotList([1, 2, 3, 4, 5]).grouping_by(lambda x: x % 2)
+        result = grouping.fold_with_count(lambda k, e: 0, lambda k, acc, e: acc + e)
+        self.assertEqual(result.to_dict(), {1: (9, 3), 0: (6, 2)})
+        self.assertEqual(
+            {k: v[0] for k, v in result.to_dict().items()},
+            grouping.fold(lambda k, e: 0, lambda k, acc, e: acc + e).to_dict()
+        )
+
+    def test_fold_with_count_average(self):
+        """fold_with_count supports computing per-group averages"""
+        words = KotList(["apple", "avocado", "banana"])
+        result = words.grouping_by(lambda w: w[0]).fold_with_count(lambda k, e: 0, lambda k, acc, e: acc + len(e))
+        averages = {k: total / count for k, (total, count) in result.to_dict().items()}
+        self.assertEqual(averages, {"a": 6.0, "b": 6.0})
+
+
+class TestKotGroupingReduceIndexed(unittest.TestCase):
+    def test_reduce_indexed_passes_group_index(self):
+        """reduce_indexed passes the element's index within its group, starting at 1"""
+        calls = []
+
+        def operation(key, index, acc, element):
+            calls.append((key, index, element))
+            return acc + element
+
+        result = KotList([1, 2, 3, 4, 5]).grouping_by(lambda x: x % 2).reduce_indexed(operation)
+        self.assertEqual(result.to_dict(), {1: 9, 0: 6})
+        self.assertEqual(calls, [(1, 1, 3), (1, 2, 5), (0, 1, 4)])
+
+    def test_single_element_group_untouched(self):
+        """A single-element group maps to its element without calling the operation"""
+        result = KotList(["x"]).grouping_by(lambda s: s).reduce_indexed(lambda k, i, acc, e: self.fail("called"))
+        self.assertEqual(result.to_dict(), {"x": "x"})
@@ -1,3 +1,4 @@
+import numbers
 import random
 import unittest
 
@@ -1345,6 +1346,32 @@ class TestKotListSublistOps(unittest.TestCase):
         self.assertEqual(lst.drop_last_while(lambda x: x < 1).to_list(), [1, 2, 3, 4, 5, 1, 2])
         self.assertEqual(lst.drop_last_while(lambda x: x < 10).to_list(), [])
 
+    def test_get_range(self):
+        lst = KotList([0, 1, 2, 3, 4, 5])
+
+        # Forward ranges
+        self.assertEqual(lst.get_range(1, 4).to_list(), [1, 2, 3])
+        self.assertEqual(lst.get_range(0, 6, 2).to_list(), [0, 2, 4])
+        self.assertEqual(lst.get_range(3, 3).to_list(), [])
+
+        # Backward ranges
+        self.assertEqual(lst.get_range(5, 0, -2).to_list(), [5, 3, 1])
+        self.assertEqual(lst.get_range(lst.last_index, -1, -1).to_list(), [5, 4, 3, 2, 1, 0])
+
+        # Invalid bounds and step
+        with self.assertRaises(ValueError):
+            lst.get_range(0, 3, 0)
+        with self.assertRaises(IndexError):
+            lst.get_range(-1, 3)
+        with self.assertRaises(IndexError):
+            lst.get_range(0, 7)
+        with self.assertRaises(IndexError):
+            lst.get_range(4, 2)
+        with self.assertRaises(IndexError):
+            lst.get_range(2, 4, -1)
+        with self.assertRaises(IndexError):
+            lst.get_range(6, 0, -1)
+
 
 class TestKotListAdvancedTransform(unittest.TestCase):
     def test_map_indexed_not_null(self):
@@ -2149,3 +2176,1145 @@ class TestKotListMaxMin(unittest.TestCase):
         empty = KotList([])
         self.assertIsNone(empty.min_or_none())
         self.assertEqual(empty.min_or_none(), empty.min_or_null())
+
+
+class TestKotListEmptyOfType(unittest.TestCase):
+    def test_empty_of_type(self):
+        """Test empty_of_type creates an empty list with a fixed element type."""
+        class Animal:
+            pass
+
+        class Dog(Animal):
+            pass
+
+        lst = KotList.empty_of_type(Animal)
+        self.assertTrue(lst.is_empty())
+        self.assertEqual(lst._element_type, Animal)
+
+    def test_empty_of_type_mutable_checks_first_add(self):
+        """Test the first add is type-checked instead of inferring the type."""
+        from kotcollections import KotMutableList
+
+        class Animal:
+            pass
+
+        class Dog(Animal):
+            pass
+
+        animals = KotMutableList.empty_of_type(Animal)
+        self.assertIsInstance(animals, KotMutableList)
+        with self.assertRaises(TypeError):
+            animals.add("not an animal")
+        animals.add(Dog())
+        self.assertEqual(animals._element_type, Animal)
+
+
+class TestKotListRandomTake(unittest.TestCase):
+    def test_random_take(self):
+        """random_take returns n distinct elements from the list"""
+        lst = KotList([1, 2, 3, 4, 5])
+        result = lst.random_take(3, random.Random(42))
+        self.assertEqual(result.size, 3)
+        self.assertEqual(len(set(result.to_list())), 3)
+        self.assertTrue(all(lst.contains(e) for e in result))
+        self.assertEqual(lst.random_take(5).sorted().to_list(), [1, 2, 3, 4, 5])
+        self.assertTrue(lst.random_take(0).is_empty())
+
+    def test_random_take_out_of_range(self):
+        """random_take raises for n out of range, random_take_or_null returns None"""
+        lst = KotList([1, 2, 3])
+        with self.assertRaises(ValueError):
+            lst.random_take(4)
+        with self.assertRaises(ValueError):
+            lst.random_take(-1)
+        self.assertIsNone(lst.random_take_or_null(4))
+        self.assertIsNone(lst.random_take_or_none(-1))
+        self.assertEqual(lst.random_take_or_none(3).size, 3)
+
+
+class TestKotListRandomWeighted(unittest.TestCase):
+    def test_random_weighted(self):
+        """random_weighted only picks elements with a positive weight"""
+        lst = KotList(["a", "b", "c"])
+        rng = random.Random(7)
+        for _ in range(20):
+            self.assertEqual(lst.random_weighted(lambda s: 1 if s == "b" else 0, rng), "b")
+
+    def test_random_weighted_take(self):
+        """random_weighted_take samples n elements with replacement"""
+        lst = KotList([1, 2, 3])
+        result = lst.random_weighted_take(10, lambda x: x, random.Random(3))
+        self.assertEqual(result.size, 10)
+        self.assertTrue(result.all(lambda x: x in (1, 2, 3)))
+        self.assertTrue(lst.random_weighted_take(0, lambda x: x).is_empty())
+
+    def test_random_weighted_invalid(self):
+        """Empty lists, zero total weight and negative counts raise ValueError"""
+        with self.assertRaises(ValueError):
+            KotList([]).random_weighted(lambda x: 1)
+        with self.assertRaises(ValueError):
+            KotList([1, 2]).random_weighted(lambda x: 0)
+        with self.assertRaises(ValueError):
+            KotList([1, 2]).random_weighted(lambda x: -x)
+        with self.assertRaises(ValueError):
+            KotList([1, 2]).random_weighted_take(-1, lambda x: 1)
+
+
+class TestKotListFormat(unittest.TestCase):
+    def test_format_with_separator(self):
+        """A non-empty format spec is used as the join separator"""
+        lst = KotList([1, 2, 3])
+        self.assertEqual(f"{lst:, }", "1, 2, 3")
+        self.assertEqual(f"{lst:-}", "1-2-3")
+        self.assertEqual(format(KotList(), "|"), "")
+
+    def test_format_empty_spec(self):
+        """An empty format spec falls back to str()"""
+        lst = KotList([1, 2, 3])
+        self.assertEqual(f"{lst}", str(lst))
+
+
+class TestKotListComparatorErrors(unittest.TestCase):
+    def test_comparator_exception_propagates(self):
+        """Exceptions from the comparator propagate with their original type"""
+        def bad(a, b):
+            raise ValueError("boom")
+
+        with self.assertRaises(ValueError) as cm:
+            KotList([2, 1]).sorted_with(bad)
+        self.assertEqual(str(cm.exception), "boom")
+        if hasattr(cm.exception, "__notes__"):
+            self.assertIn("comparator raised during sort", cm.exception.__notes__)
+
+    def test_comparator_non_number_result(self):
+        """A comparator returning something other than a number raises TypeError"""
+        with self.assertRaises(TypeError):
+            KotList(["b", "a"]).sorted_with(lambda a, b: None)
+        with self.assertRaises(TypeError):
+            KotList(["b", "a"]).sorted_with(lambda a, b: "a")
+
+    def test_float_result_allowed(self):
+        """A comparator returning a float, such as a - b on floats, is accepted"""
+        self.assertEqual(KotList([2.5, 1.5, 3.5]).sorted_with(lambda a, b: a - b).to_list(), [1.5, 2.5, 3.5])
+
+    def test_bool_result_allowed(self):
+        """bool is an int subclass and is accepted"""
+        self.assertEqual(KotList([1, 2]).sorted_with(lambda a, b: a < b).to_list(), [1, 2])
+
+
+class TestKotListWindowedSteps(unittest.TestCase):
+    def test_windowed_step_size_combinations(self):
+        """windowed matches Kotlin for step < size, step == size and step > size"""
+        lst = KotList(range(7))
+        cases = [
+            (3, 1, False, [[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5], [4, 5, 6]]),
+            (3, 1, True, [[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5], [4, 5, 6], [5, 6], [6]]),
+            (3, 2, False, [[0, 1, 2], [2, 3, 4], [4, 5, 6]]),
+            (3, 2, True, [[0, 1, 2], [2, 3, 4], [4, 5, 6], [6]]),
+            (2, 2, False, [[0, 1], [2, 3], [4, 5]]),
+            (2, 2, True, [[0, 1], [2, 3], [4, 5], [6]]),
+            (2, 3, False, [[0, 1], [3, 4]]),
+            (2, 3, True, [[0, 1], [3, 4], [6]]),
+            (3, 3, False, [[0, 1, 2], [3, 4, 5]]),
+            (1, 4, False, [[0], [4]]),
+            (8, 1, False, []),
+            (8, 1, True, [[0, 1, 2, 3, 4, 5, 6], [1, 2, 3, 4, 5, 6], [2, 3, 4, 5, 6],
+                          [3, 4, 5, 6], [4, 5, 6], [5, 6], [6]]),
+        ]
+        for size, step, partial, expected in cases:
+            with self.subTest(size=size, step=step, partial_windows=partial):
+                result = lst.windowed(size, step, partial)
+                self.assertEqual([w.to_list() for w in result], expected)
+
+
+class TestKotListChunkedWithRemainder(unittest.TestCase):
+    def test_chunked_with_remainder(self):
+        """Full chunks and the leftover elements are returned separately"""
+        chunks, remainder = KotList([1, 2, 3, 4, 5]).chunked_with_remainder(2)
+        self.assertEqual([c.to_list() for c in chunks], [[1, 2], [3, 4]])
+        self.assertEqual(remainder.to_list(), [5])
+
+    def test_chunked_with_remainder_exact_and_short(self):
+        """The remainder is empty on exact multiples and holds everything for short lists"""
+        chunks, remainder = KotList([1, 2, 3, 4]).chunked_with_remainder(2)
+        self.assertEqual(chunks.size, 2)
+        self.assertTrue(remainder.is_empty())
+        chunks, remainder = KotList([1, 2]).chunked_with_remainder(3)
+        self.assertTrue(chunks.is_empty())
+        self.assertEqual(remainder.to_list(), [1, 2])
+        with self.assertRaises(ValueError):
+            KotList([1]).chunked_with_remainder(0)
+
+    def test_chunked_with_remainder_preserves_type(self):
+        """Inner lists and the remainder carry the element type"""
+        chunks, remainder = KotList.of_type(int, [1, 2, 3]).chunked_with_remainder(2)
+        self.assertEqual(chunks[0].element_type, int)
+        self.assertEqual(remainder.element_type, int)
+
+
+class TestKotListToKotMap(unittest.TestCase):
+    def test_to_kot_map(self):
+        """A list of pairs converts to a map; later pairs win on duplicate keys"""
+        m = KotList([("a", 1), ("b", 2), ("a", 3)]).to_kot_map()
+        self.assertEqual(m.to_dict(), {"a": 3, "b": 2})
+        self.assertEqual(KotList([["x", 1]]).to_kot_map().to_dict(), {"x": 1})
+        self.assertTrue(KotList().to_kot_map().is_empty())
+
+    def test_to_kot_map_invalid_elements(self):
+        """Elements that are not 2-sequences raise ValueError"""
+        with self.assertRaises(ValueError):
+            KotList([(1, 2, 3)]).to_kot_map()
+        with self.assertRaises(ValueError):
+            KotList(["ab"]).to_kot_map()
+
+    def test_to_kot_map_of_types(self):
+        """Typed conversion validates keys and values"""
+        m = KotList([("a", 1)]).to_kot_map_of_types(str, int)
+        self.assertEqual(m.get("a"), 1)
+        with self.assertRaises(TypeError):
+            KotList([("a", "x")]).to_kot_map_of_types(str, int)
+
+
+class TestKotListIndexBounds(unittest.TestCase):
+    def test_index_range(self):
+        """index_range returns the inclusive index bounds, consistent with indices"""
+        lst = KotList(["a", "b", "c"])
+        self.assertEqual(lst.index_range(), (0, 2))
+        self.assertEqual(lst.indices, range(0, 3))
+        first, last = lst.index_range()
+        self.assertEqual(range(first, last + 1), lst.indices)
+        self.assertIn(2, lst.indices)
+
+    def test_empty_list_bounds(self):
+        """An empty list has last_index -1, an empty indices range and bounds (0, -1)"""
+        lst = KotList()
+        self.assertEqual(lst.last_index, -1)
+        self.assertEqual(lst.indices, range(0))
+        self.assertEqual(len(lst.indices), 0)
+        self.assertEqual(lst.index_range(), (0, -1))
+        first, last = lst.index_range()
+        self.assertEqual(range(first, last + 1), lst.indices)
+
+
+class TestKotListPrepend(unittest.TestCase):
+    def test_prepend(self):
+        """prepend returns a new list with the element at the front"""
+        lst = KotList([2, 3])
+        self.assertEqual(lst.prepend(1).to_list(), [1, 2, 3])
+        self.assertEqual(lst.to_list(), [2, 3])
+        self.assertEqual(KotList().prepend("a").to_list(), ["a"])
+
+    def test_prepend_all(self):
+        """prepend_all keeps the order of the prepended elements"""
+        lst = KotList([3])
+        self.assertEqual(lst.prepend_all([1, 2]).to_list(), [1, 2, 3])
+        self.assertEqual(lst.prepend_all(KotList([0])).to_list(), [0, 3])
+        self.assertEqual(lst.prepend_all([]).to_list(), [3])
+
+    def test_prepend_preserves_type(self):
+        """The element type is kept and enforced"""
+        lst = KotList.of_type(object, [1])
+        self.assertEqual(lst.prepend("a").element_type, object)
+        with self.assertRaises(TypeError):
+            KotList.of_type(int, [1]).prepend("a")
+
+
+class TestKotListIndexedWhile(unittest.TestCase):
+    def test_take_while_indexed(self):
+        """take_while_indexed stops at the first index/element failing the predicate"""
+        lst = KotList([5, 6, 7, 1, 9])
+        self.assertEqual(lst.take_while_indexed(lambda i, x: i < 2 and x > 3).to_list(), [5, 6])
+        self.assertEqual(lst.take_while_indexed(lambda i, x: x > 3).to_list(), [5, 6, 7])
+        self.assertEqual(lst.take_while_indexed(lambda i, x: True).to_list(), [5, 6, 7, 1, 9])
+        self.assertTrue(lst.take_while_indexed(lambda i, x: False).is_empty())
+
+    def test_drop_while_indexed(self):
+        """drop_while_indexed drops the leading elements satisfying the predicate"""
+        lst = KotList([5, 6, 7, 1, 9])
+        self.assertEqual(lst.drop_while_indexed(lambda i, x: i < 2 and x > 3).to_list(), [7, 1, 9])
+        self.assertTrue(lst.drop_while_indexed(lambda i, x: True).is_empty())
+        self.assertEqual(lst.drop_while_indexed(lambda i, x: False).to_list(), [5, 6, 7, 1, 9])
+
+    def test_indexed_while_preserves_type(self):
+        """Results keep the element type"""
+        lst = KotList.of_type(int, [1, 2, 3])
+        self.assertEqual(lst.take_while_indexed(lambda i, x: i < 1).element_type, int)
+        self.assertEqual(lst.drop_while_indexed(lambda i, x: i < 1).element_type, int)
+
+
+class TestKotListFindOrElse(unittest.TestCase):
+    def test_find_or_else(self):
+        """find_or_else returns a falsy match instead of the default"""
+        lst = KotList([3, 0, 4, 0])
+        self.assertEqual(lst.find_or_else(lambda x: x == 0, lambda: -1), 0)
+        self.assertEqual(lst.find_or_else(lambda x: x > 3, lambda: -1), 4)
+        self.assertEqual(lst.find_or_else(lambda x: x > 10, lambda: -1), -1)
+
+    def test_find_last_or_else(self):
+        """find_last_or_else searches from the end"""
+        lst = KotList([1, 2, 3, 4])
+        self.assertEqual(lst.find_last_or_else(lambda x: x % 2 == 1, lambda: 0), 3)
+        self.assertEqual(lst.find_last_or_else(lambda x: x > 10, lambda: 0), 0)
+
+    def test_default_only_called_when_needed(self):
+        """The default function is not invoked when a match exists"""
+        calls = []
+        KotList([1]).find_or_else(lambda x: True, lambda: calls.append(1))
+        self.assertEqual(calls, [])
+
+
+class TestKotListCountDistinct(unittest.TestCase):
+    def test_count_distinct(self):
+        """count_distinct counts unique elements"""
+        self.assertEqual(KotList([1, 2, 2, 3, 1]).count_distinct(), 3)
+        self.assertEqual(KotList().count_distinct(), 0)
+
+    def test_count_distinct_by(self):
+        """count_distinct_by counts unique selector keys"""
+        words = KotList(["apple", "avocado", "banana", "cherry"])
+        self.assertEqual(words.count_distinct_by(lambda s: s[0]), 3)
+        self.assertEqual(words.count_distinct_by(len), 3)
+
+    def test_count_distinct_unhashable(self):
+        """count_distinct rejects unhashable elements with the same message style as count_by"""
+        with self.assertRaisesRegex(TypeError, "count_distinct element must be hashable, got unhashable type 'list'"):
+            KotList([[1], [2]]).count_distinct()
+
+
+class TestKotListAssociateWithOnce(unittest.TestCase):
+    def test_selector_runs_once_per_distinct_element(self):
+        """Duplicate elements do not re-run the value selector"""
+        calls = []
+
+        def selector(x):
+            calls.append(x)
+            return x * 10
+
+        result = KotList([1, 2, 1, 3, 2, 1]).associate_with(selector)
+        self.assertEqual(result.to_dict(), {1: 10, 2: 20, 3: 30})
+        self.assertEqual(calls, [1, 2, 3])
+
+    def test_key_type_follows_element_type(self):
+        """The resulting map uses the list's element type as its key type"""
+        result = KotList.of_type(object, [1, "a"]).associate_with(str)
+        self.assertIs(result._key_type, object)
+        self.assertEqual(result.get("a"), "a")
+        self.assertIs(KotList([1]).associate_with(str)._key_type, int)
+
+
+class TestKotListReduceOrElse(unittest.TestCase):
+    def test_reduce_or_else(self):
+        """reduce_or_else reduces non-empty lists and falls back on empty ones"""
+        self.assertEqual(KotList([1, 2, 3]).reduce_or_else(lambda a, b: a + b, lambda: -1), 6)
+        self.assertEqual(KotList().reduce_or_else(lambda a, b: a + b, lambda: -1), -1)
+
+    def test_default_can_raise_custom_error(self):
+        """The fallback can raise a domain-specific error"""
+        def fail():
+            raise LookupError("no scores")
+
+        with self.assertRaises(LookupError):
+            KotList().reduce_or_else(max, fail)
+
+
+class TestKotListExtremeTieBreaker(unittest.TestCase):
+    def test_ties_default_to_first(self):
+        """Without a tie breaker the first extreme element wins"""
+        words = KotList(["bb", "aa", "cc", "d"])
+        self.assertEqual(words.max_by(len), "bb")
+        self.assertEqual(words.min_by(lambda s: -len(s)), "bb")
+
+    def test_tie_breaker(self):
+        """Elements with equal primary keys are ordered by the tie breaker"""
+        words = KotList(["bb", "aa", "cc", "d"])
+        self.assertEqual(words.max_by(len, tie_breaker=lambda s: s), "cc")
+        self.assertEqual(words.min_by(lambda s: -len(s), tie_breaker=lambda s: s), "aa")
+        self.assertEqual(words.max_by_or_null(len, lambda s: s), "cc")
+        self.assertEqual(words.min_by_or_none(lambda s: -len(s), lambda s: s), "aa")
+        self.assertIsNone(KotList().max_by_or_none(len, lambda s: s))
+
+    def test_last_on_ties_via_index(self):
+        """A tie breaker over the index selects the last extreme element"""
+        pairs = KotList([(1, "a"), (3, "b"), (3, "c")])
+        indexed = KotList(list(pairs.with_index()))
+        self.assertEqual(indexed.max_by(lambda p: p[1][0], tie_breaker=lambda p: p[0])[1], (3, "c"))
+
+
+class TestKotListContainsIndex(unittest.TestCase):
+    def test_in_operator(self):
+        """The in operator uses contains"""
+        lst = KotList(range(1000))
+        self.assertIn(999, lst)
+        self.assertNotIn(1000, lst)
+        self.assertTrue(lst.contains(0))
+        self.assertFalse(KotList().contains(0))
+
+    def test_repeated_checks_use_cached_index(self):
+        """The hash index is built once and reused"""
+        lst = KotList(["a", "b"])
+        self.assertTrue(lst.contains("a"))
+        index = lst._element_index()
+        self.assertIsNotNone(index)
+        self.assertTrue(lst.contains("b"))
+        self.assertIs(lst._element_index(), index)
+
+    def test_unhashable_elements_fall_back(self):
+        """Unhashable elements and lookups are handled by a linear scan"""
+        lst = KotList([[1], [2]])
+        self.assertIn([2], lst)
+        self.assertNotIn([3], lst)
+        self.assertFalse(KotList([1, 2]).contains([1]))
+
+
+class TestKotListExactSums(unittest.TestCase):
+    def test_sum(self):
+        """sum adds all elements; empty lists sum to 0"""
+        self.assertEqual(KotList([1, 2, 3]).sum(), 6)
+        self.assertEqual(KotList().sum(), 0)
+        self.assertAlmostEqual(KotList([0.5, 0.25]).sum(), 0.75)
+
+    def test_decimal_and_fraction_stay_exact(self):
+        """Decimal and Fraction values are not converted to float"""
+        from decimal import Decimal
+        from fractions import Fraction
+        decimals = KotList([Decimal("0.1")] * 3)
+        self.assertEqual(decimals.sum(), Decimal("0.3"))
+        self.assertIsInstance(decimals.sum(), Decimal)
+        self.assertEqual(decimals.sum_of(lambda d: d * 2), Decimal("0.6"))
+        fractions = KotList([Fraction(1, 3)] * 3)
+        self.assertEqual(fractions.sum(), Fraction(1))
+        self.assertEqual(fractions.sum_of(lambda f: f / 2), Fraction(1, 2))
+
+
+class TestKotListGroupByKeys(unittest.TestCase):
+    def test_group_by_preserves_orders(self):
+        """Groups keep first-seen key order and elements keep their order within groups"""
+        result = KotList(["b1", "a1", "b2", "a2", "c1"]).group_by(lambda s: s[0])
+        self.assertEqual(list(result.to_dict()), ["b", "a", "c"])
+        self.assertEqual(result.get("b").to_list(), ["b1", "b2"])
+        self.assertEqual(result.get("a").to_list(), ["a1", "a2"])
+
+    def test_unhashable_keys_raise_clear_error(self):
+        """Unhashable keys raise a TypeError naming the problem"""
+        with self.assertRaises(TypeError) as cm:
+            KotList([1, 2]).group_by(lambda x: [x])
+        self.assertIn("must be hashable", str(cm.exception))
+        self.assertIn("'list'", str(cm.exception))
+        with self.assertRaises(TypeError):
+            KotList([1, 2]).group_by_with_value(lambda x: {x: x}, lambda x: x)
+
+    def test_hashable_key_alternative(self):
+        """Converting keys to tuples groups structurally equal keys"""
+        result = KotList([[1, 2], [1, 2], [3]]).group_by(tuple)
+        self.assertEqual(result.get((1, 2)).size, 2)
+
+
+class TestKotListDistinctBySelectorCalls(unittest.TestCase):
+    def test_selector_runs_once_per_element(self):
+        """distinct_by invokes the selector exactly once per element"""
+        calls = []
+
+        def selector(x):
+            calls.append(x)
+            return x % 3
+
+        result = KotList([1, 2, 3, 4, 5, 6, 7]).distinct_by(selector)
+        self.assertEqual(result.to_list(), [1, 2, 3])
+        self.assertEqual(calls, [1, 2, 3, 4, 5, 6, 7])
+
+
+class TestKotListIsInstanceAccess(unittest.TestCase):
+    def setUp(self):
+        self.items = KotList.of_type(object, [1, "a", 2.5, "b", 3])
+
+    def test_first_is_instance(self):
+        """first_is_instance returns the first element of the given class"""
+        self.assertEqual(self.items.first_is_instance(str), "a")
+        self.assertEqual(self.items.first_is_instance(float), 2.5)
+        with self.assertRaises(ValueError):
+            self.items.first_is_instance(bytes)
+
+    def test_first_is_instance_or_null(self):
+        """first_is_instance_or_null returns None when no element matches"""
+        self.assertEqual(self.items.first_is_instance_or_null(int), 1)
+        self.assertIsNone(self.items.first_is_instance_or_none(bytes))
+
+    def test_last_is_instance(self):
+        """last_is_instance returns the last element of the given class"""
+        self.assertEqual(self.items.last_is_instance(str), "b")
+        self.assertEqual(self.items.last_is_instance_or_null(int), 3)
+        self.assertIsNone(self.items.last_is_instance_or_none(bytes))
+        with self.assertRaises(ValueError):
+            KotList().last_is_instance(int)
+
+
+class TestKotListDistinctKeepLast(unittest.TestCase):
+    def test_distinct_keep_last(self):
+        """keep_last keeps the last occurrence, ordered by last occurrence"""
+        lst = KotList([1, 2, 1, 3, 2])
+        self.assertEqual(lst.distinct().to_list(), [1, 2, 3])
+        self.assertEqual(lst.distinct(keep_last=True).to_list(), [1, 3, 2])
+
+    def test_distinct_by_keep_last(self):
+        """Latest record wins per key"""
+        records = KotList([("a", 1), ("b", 1), ("a", 2), ("c", 1), ("b", 2)])
+        self.assertEqual(records.distinct_by(lambda r: r[0]).to_list(), [("a", 1), ("b", 1), ("c", 1)])
+        self.assertEqual(records.distinct_by(lambda r: r[0], keep_last=True).to_list(),
+                         [("a", 2), ("c", 1), ("b", 2)])
+        self.assertTrue(KotList().distinct_by(lambda r: r, keep_last=True).is_empty())
+
+
+class TestKotListIndexValidation(unittest.TestCase):
+    def test_non_integer_indices_rejected(self):
+        """Non-integer indices raise a clear TypeError"""
+        lst = KotList([1, 2, 3])
+        for bad in (1.0, "1", None):
+            with self.subTest(index=bad):
+                with self.assertRaises(TypeError) as cm:
+                    lst.get(bad)
+                self.assertIn("Index must be an integer", str(cm.exception))
+        with self.assertRaises(TypeError):
+            lst[1.5]
+        with self.assertRaises(TypeError):
+            lst.get_or_null("0")
+        with self.assertRaises(TypeError):
+            lst.sub_list(0, 2.0)
+        with self.assertRaises(TypeError):
+            lst.get_range(0, 3, 1.0)
+
+    def test_index_like_objects_accepted(self):
+        """Objects implementing __index__ work as indices"""
+        class Idx:
+            def __index__(self):
+                return 1
+
+        lst = KotList([1, 2, 3])
+        self.assertEqual(lst.get(Idx()), 2)
+        self.assertEqual(lst.element_at_or_null(Idx()), 2)
+
+    def test_out_of_bounds_messages_use_passed_index(self):
+        """Huge and negative indices are reported as passed"""
+        lst = KotList([1])
+        with self.assertRaises(IndexError) as cm:
+            lst.get(2 ** 70)
+        self.assertIn(str(2 ** 70), str(cm.exception))
+        with self.assertRaises(IndexError) as cm:
+            lst[-1]
+        self.assertIn("Index -1", str(cm.exception))
+        self.assertIsNone(lst.get_or_null(-5))
+
+
+class TestKotListFlattenToMap(unittest.TestCase):
+    def test_flatten_to_map_last_wins(self):
+        """flatten_to_map merges maps with later values winning by default"""
+        from kotcollections import KotMap
+        lst = KotList([KotMap({"a": 1, "b": 2}), KotMap({"b": 3, "c": 4})])
+        self.assertEqual(lst.flatten_to_map().to_dict(), {"a": 1, "b": 3, "c": 4})
+
+    def test_flatten_to_map_first_wins(self):
+        """flatten_to_map keeps the earliest value with on_conflict='first'"""
+        lst = KotList([{"a": 1, "b": 2}, {"b": 3, "c": 4}])
+        self.assertEqual(lst.flatten_to_map(on_conflict="first").to_dict(), {"a": 1, "b": 2, "c": 4})
+
+    def test_flatten_to_map_errors(self):
+        """flatten_to_map rejects non-map elements and unknown conflict policies"""
+        with self.assertRaises(TypeError):
+            KotList([1, 2]).flatten_to_map()
+        with self.assertRaises(ValueError):
+            KotList([{"a": 1}]).flatten_to_map(on_conflict="merge")
+        self.assertTrue(KotList().flatten_to_map().is_empty())
+
+
+class TestKotListPartitionByTransform(unittest.TestCase):
+    def test_partition_by_transform(self):
+        """partition_by_transform routes transformed values by their is_right flag"""
+        lst = KotList(["1", "x", "3", "y"])
+        errors, values = lst.partition_by_transform(
+            lambda s: (True, int(s)) if s.isdigit() else (False, f"bad: {s}")
+        )
+        self.assertEqual(errors.to_list(), ["bad: x", "bad: y"])
+        self.assertEqual(values.to_list(), [1, 3])
+
+    def test_partition_by_transform_empty(self):
+        """partition_by_transform on an empty list returns two empty lists"""
+        left, right = KotList().partition_by_transform(lambda e: (True, e))
+        self.assertTrue(left.is_empty())
+        self.assertTrue(right.is_empty())
+
+
+class TestKotListZipLongest(unittest.TestCase):
+    def test_zip_longest_pads_shorter_side(self):
+        """zip_longest pads whichever side is shorter with fill_value"""
+        lst = KotList([1, 2, 3])
+        self.assertEqual(lst.zip_longest(["a"]).to_list(), [(1, "a"), (2, None), (3, None)])
+        self.assertEqual(KotList([1]).zip_longest(iter("abc"), fill_value=0).to_list(),
+                         [(1, "a"), (0, "b"), (0, "c")])
+
+    def test_zip_longest_equal_and_empty(self):
+        """zip_longest behaves like zip for equal lengths and handles empty inputs"""
+        self.assertEqual(KotList([1, 2]).zip_longest(KotList(["a", "b"])).to_list(), [(1, "a"), (2, "b")])
+        self.assertEqual(KotList().zip_longest([]).to_list(), [])
+
+
+class TestKotListCross(unittest.TestCase):
+    def test_cross_pairs(self):
+        """cross pairs every element with every element of other"""
+        self.assertEqual(KotList([1, 2]).cross(["a", "b"]).to_list(),
+                         [(1, "a"), (1, "b"), (2, "a"), (2, "b")])
+
+    def test_cross_with_transform_and_iterator(self):
+        """cross applies the transform and accepts a one-shot iterator"""
+        self.assertEqual(KotList([1, 2]).cross(iter([10, 20]), lambda a, b: a * b).to_list(), [10, 20, 20, 40])
+        self.assertTrue(KotList([1]).cross([]).is_empty())
+
+
+class TestKotListMovingAggregate(unittest.TestCase):
+    def test_moving_aggregate_matches_windowed_map(self):
+        """moving_aggregate gives the same results as windowed(...).map(...)"""
+        lst = KotList([1, 2, 3, 4, 5])
+        op = lambda w: w.sum() / w.size
+        self.assertEqual(lst.moving_aggregate(3, op).to_list(), [2.0, 3.0, 4.0])
+        self.assertEqual(lst.moving_aggregate(2, op, step=2).to_list(), lst.windowed(2, 2).map(op).to_list())
+
+    def test_moving_aggregate_short_list_and_invalid_args(self):
+        """moving_aggregate returns empty for too-short lists and rejects non-positive sizes"""
+        self.assertTrue(KotList([1]).moving_aggregate(2, lambda w: w.sum()).is_empty())
+        with self.assertRaises(ValueError):
+            KotList([1]).moving_aggregate(0, lambda w: w.sum())
+
+
+class TestKotListInterleave(unittest.TestCase):
+    def test_interleave_with_longer_side_tail(self):
+        """interleave alternates elements and appends the remaining tail"""
+        self.assertEqual(KotList([1, 2, 3]).interleave([10]).to_list(), [1, 10, 2, 3])
+        self.assertEqual(KotList([1]).interleave(iter([10, 20, 30])).to_list(), [1, 10, 20, 30])
+        self.assertEqual(KotList([1, 2]).interleave(KotList([10, 20])).to_list(), [1, 10, 2, 20])
+
+    def test_interleave_mixed_types(self):
+        """interleave allows different element types and returns a KotList[object]"""
+        result = KotList([1, 2]).interleave(["a", "b"])
+        self.assertEqual(result.to_list(), [1, "a", 2, "b"])
+        self.assertEqual(result.element_type, object)
+        self.assertEqual(KotList().interleave([]).to_list(), [])
+
+
+class TestKotListChunkedBy(unittest.TestCase):
+    def test_chunked_by_runs(self):
+        """chunked_by groups consecutive equal elements into runs"""
+        chunks = KotList([1, 1, 2, 3, 3, 1]).chunked_by(lambda a, b: a != b)
+        self.assertEqual([c.to_list() for c in chunks], [[1, 1], [2], [3, 3], [1]])
+
+    def test_chunked_by_boundaries_and_types(self):
+        """chunked_by splits at gaps and keeps the element type in each chunk"""
+        lst = KotList.of_type(int, [1, 2, 5, 6, 10])
+        chunks = lst.chunked_by(lambda a, b: b - a > 1)
+        self.assertEqual([c.to_list() for c in chunks], [[1, 2], [5, 6], [10]])
+        self.assertTrue(all(c.element_type is int for c in chunks))
+        self.assertTrue(KotList().chunked_by(lambda a, b: True).is_empty())
+
+
+class TestKotListSplitAt(unittest.TestCase):
+    def test_split_at(self):
+        """split_at returns the prefix before index and the suffix from index"""
+        prefix, suffix = KotList([1, 2, 3, 4]).split_at(1)
+        self.assertEqual((prefix.to_list(), suffix.to_list()), ([1], [2, 3, 4]))
+
+    def test_split_at_clamps_index(self):
+        """split_at clamps negative and too-large indices"""
+        lst = KotList([1, 2])
+        self.assertEqual([p.to_list() for p in lst.split_at(-3)], [[], [1, 2]])
+        self.assertEqual([p.to_list() for p in lst.split_at(10)], [[1, 2], []])
+
+    def test_split_at_mutable_list(self):
+        """split_at on a KotMutableList returns copies unaffected by later mutation"""
+        from kotcollections import KotMutableList
+        lst = KotMutableList([1, 2, 3])
+        prefix, suffix = lst.split_at(2)
+        lst.add(4)
+        self.assertEqual((prefix.to_list(), suffix.to_list()), ([1, 2], [3]))
+
+
+class TestKotListRotated(unittest.TestCase):
+    def test_rotated(self):
+        """rotated shifts right for positive n and left for negative n, modulo the size"""
+        lst = KotList([1, 2, 3, 4])
+        self.assertEqual(lst.rotated(1).to_list(), [4, 1, 2, 3])
+        self.assertEqual(lst.rotated(-1).to_list(), [2, 3, 4, 1])
+        self.assertEqual(lst.rotated(6).to_list(), [3, 4, 1, 2])
+        self.assertEqual(lst.rotated(4).to_list(), [1, 2, 3, 4])
+        self.assertEqual(lst.to_list(), [1, 2, 3, 4])
+
+    def test_rotated_empty_and_typed(self):
+        """rotated handles empty lists and preserves the element type"""
+        self.assertEqual(KotList().rotated(3).to_list(), [])
+        self.assertIs(KotList.of_type(int, [1, 2]).rotated(1).element_type, int)
+
+    def test_rotated_rejects_non_integer_distance(self):
+        """rotated reports a non-integer distance as such, even on an empty list"""
+        with self.assertRaisesRegex(TypeError, "Rotation distance must be an integer, got 'float'"):
+            KotList([1, 2]).rotated(1.5)
+        with self.assertRaisesRegex(TypeError, "Rotation distance must be an integer"):
+            KotList().rotated("1")
+
+
+class TestKotListDiff(unittest.TestCase):
+    def test_diff(self):
+        """diff reports added, removed and common elements in order"""
+        result = KotList([1, 2, 3, 2]).diff([2, 4, 3, 5])
+        self.assertEqual(result["added"].to_list(), [4, 5])
+        self.assertEqual(result["removed"].to_list(), [1])
+        self.assertEqual(result["common"].to_list(), [2, 3, 2])
+
+    def test_diff_unhashable_and_empty(self):
+        """diff compares by equality, so unhashable elements are supported"""
+        result = KotList([[1], [2]]).diff(iter([[2], [3]]))
+        self.assertEqual(result["added"].to_list(), [[3]])
+        self.assertEqual(result["removed"].to_list(), [[1]])
+        self.assertEqual(result["common"].to_list(), [[2]])
+        empty = KotList().diff([])
+        self.assertTrue(all(empty[key].is_empty() for key in ("added", "removed", "common")))
+
+
+class TestKotListCountBy(unittest.TestCase):
+    def test_count_by_selector(self):
+        """count_by tallies elements per selected key in first-seen order"""
+        result = KotList(["apple", "banana", "avocado", "cherry", "blueberry"]).count_by(lambda s: s[0])
+        self.assertEqual(list(result.to_dict().items()), [("a", 2), ("b", 2), ("c", 1)])
+
+    def test_count_by_elements(self):
+        """count_by without a selector counts each element"""
+        self.assertEqual(KotList([1, 2, 1, 1]).count_by().to_dict(), {1: 3, 2: 1})
+        self.assertTrue(KotList().count_by().is_empty())
+
+    def test_count_by_unhashable_key(self):
+        """count_by rejects unhashable keys with a clear TypeError"""
+        with self.assertRaises(TypeError):
+            KotList([[1], [2]]).count_by()
+
+
+class TestKotListMostCommon(unittest.TestCase):
+    def test_most_common(self):
+        """most_common sorts by descending count with ties in first-appearance order"""
+        lst = KotList(["b", "a", "c", "a", "b", "d", "a"])
+        self.assertEqual(lst.most_common().to_list(), [("a", 3), ("b", 2), ("c", 1), ("d", 1)])
+        self.assertEqual(lst.most_common(2).to_list(), [("a", 3), ("b", 2)])
+        self.assertEqual(lst.most_common(0).to_list(), [])
+
+    def test_most_common_invalid_and_empty(self):
+        """most_common rejects negative n and returns empty for an empty list"""
+        with self.assertRaises(ValueError):
+            KotList([1]).most_common(-1)
+        self.assertTrue(KotList().most_common().is_empty())
+
+
+class TestKotListToCounter(unittest.TestCase):
+    def test_to_counter(self):
+        """to_counter counts elements or selector keys into a collections.Counter"""
+        from collections import Counter
+        lst = KotList(["apple", "avocado", "banana", "apple"])
+        self.assertEqual(lst.to_counter(), Counter({"apple": 2, "avocado": 1, "banana": 1}))
+        self.assertEqual(lst.to_counter(lambda s: s[0]), Counter({"a": 3, "b": 1}))
+        self.assertEqual(KotList().to_counter(), Counter())
+
+    def test_to_counter_unhashable(self):
+        """to_counter raises TypeError for unhashable elements"""
+        with self.assertRaises(TypeError) as cm:
+            KotList([[1]]).to_counter()
+        self.assertIn("hashable", str(cm.exception))
+
+
+class TestKotListWindowedAverage(unittest.TestCase):
+    def test_windowed_average(self):
+        """windowed_average returns float means of each full window"""
+        result = KotList([1, 2, 3, 4]).windowed_average(2)
+        self.assertEqual(result.to_list(), [1.5, 2.5, 3.5])
+        self.assertTrue(all(isinstance(v, float) for v in KotList([2, 4]).windowed_average(1)))
+
+    def test_windowed_average_partial_windows(self):
+        """windowed_average includes trailing partial windows when requested"""
+        lst = KotList([1, 2, 3, 4, 5])
+        self.assertEqual(lst.windowed_average(2, step=2).to_list(), [1.5, 3.5])
+        self.assertEqual(lst.windowed_average(2, step=2, partial_windows=True).to_list(), [1.5, 3.5, 5.0])
+        self.assertTrue(KotList().windowed_average(3).is_empty())
+
+
+class TestKotListCumulative(unittest.TestCase):
+    def test_cumulative_sum(self):
+        """cumulative_sum returns running totals, keeping ints as ints"""
+        result = KotList([1, 2, 3]).cumulative_sum()
+        self.assertEqual(result.to_list(), [1, 3, 6])
+        self.assertTrue(all(isinstance(v, int) for v in result))
+        self.assertEqual(KotList.of_type(object, [1, 2.5, 3]).cumulative_sum().to_list(), [1.0, 3.5, 6.5])
+        self.assertTrue(KotList().cumulative_sum().is_empty())
+
+    def test_cumulative_max_and_min(self):
+        """cumulative_max and cumulative_min track the running extremes"""
+        lst = KotList([3, 1, 4, 1, 5, 2])
+        self.assertEqual(lst.cumulative_max().to_list(), [3, 3, 4, 4, 5, 5])
+        self.assertEqual(lst.cumulative_min().to_list(), [3, 1, 1, 1, 1, 1])
+        self.assertTrue(KotList().cumulative_max().is_empty())
+
+    def test_cumulative_max_and_min_keep_original_elements(self):
+        """Mixed int/float input yields the original elements rather than float conversions"""
+        lst = KotList.of_type(numbers.Real, [1, 2.5, 3])
+        self.assertEqual([type(v) for v in lst.cumulative_max()], [int, float, int])
+        self.assertIs(lst.cumulative_max().element_type, numbers.Real)
+        self.assertEqual([type(v) for v in lst.cumulative_min()], [int, int, int])
+        self.assertIs(KotList.of_type(int, [2, 1]).cumulative_min().element_type, int)
+
+
+class TestKotListAverageOrNull(unittest.TestCase):
+    def test_average_or_null(self):
+        """average_or_null returns the mean, or None instead of NaN for an empty list"""
+        import math
+        self.assertEqual(KotList([1, 2, 3, 4]).average_or_null(), 2.5)
+        self.assertIsNone(KotList().average_or_null())
+        self.assertIsNone(KotList().average_or_none())
+        self.assertTrue(math.isnan(KotList().average()))
+
+
+class TestKotListMedianPercentile(unittest.TestCase):
+    def test_median(self):
+        """median returns the middle element, or the mean of the two middle elements"""
+        self.assertEqual(KotList([3, 1, 2]).median(), 2)
+        self.assertEqual(KotList([4, 1, 3, 2]).median(), 2.5)
+        with self.assertRaises(ValueError):
+            KotList().median()
+
+    def test_percentile(self):
+        """percentile interpolates linearly between ranks"""
+        lst = KotList([4, 1, 3, 2])
+        self.assertEqual(lst.percentile(0), 1)
+        self.assertEqual(lst.percentile(100), 4)
+        self.assertEqual(lst.percentile(50), 2.5)
+        self.assertAlmostEqual(lst.percentile(25), 1.75)
+        self.assertEqual(KotList([7]).percentile(90), 7)
+
+    def test_percentile_decimal(self):
+        """percentile interpolates Decimal data in Decimal arithmetic"""
+        from decimal import Decimal
+        lst = KotList([Decimal("1.0"), Decimal("2.0"), Decimal("4.0")])
+        self.assertEqual(lst.percentile(50), Decimal("2.0"))
+        self.assertEqual(lst.percentile(75), Decimal("3.0"))
+        self.assertIsInstance(lst.percentile(75), Decimal)
+        self.assertEqual(lst.percentile(100), Decimal("4.0"))
+
+    def test_percentile_errors(self):
+        """percentile rejects empty lists and out-of-range percentiles"""
+        with self.assertRaises(ValueError):
+            KotList().percentile(50)
+        with self.assertRaises(ValueError):
+            KotList([1]).percentile(101)
+
+
+class TestKotListVariance(unittest.TestCase):
+    def test_population_and_sample(self):
+        """variance and standard_deviation support population and sample formulas"""
+        lst = KotList([2, 4, 4, 4, 5, 5, 7, 9])
+        self.assertEqual(lst.variance(), 4.0)
+        self.assertEqual(lst.standard_deviation(), 2.0)
+        self.assertAlmostEqual(lst.variance(sample=True), 32 / 7)
+
+    def test_precision_with_large_offset(self):
+        """variance stays accurate for values with a large common offset"""
+        lst = KotList([1e9 + 4, 1e9 + 7, 1e9 + 13, 1e9 + 16])
+        self.assertAlmostEqual(lst.variance(sample=True), 30.0)
+
+    def test_errors(self):
+        """variance raises ValueError for empty input and single-element samples"""
+        with self.assertRaises(ValueError):
+            KotList().variance()
+        with self.assertRaises(ValueError):
+            KotList([1]).standard_deviation(sample=True)
+        self.assertEqual(KotList([1]).variance(), 0.0)
+
+
+class TestKotListAggregateBy(unittest.TestCase):
+    def test_aggregate_by(self):
+        """aggregate_by summarizes each group in first-seen key order"""
+        words = KotList(["apple", "bob", "avocado", "cat", "banana"])
+        result = words.aggregate_by(lambda w: w[0], lambda g: g.sum_of(len))
+        self.assertEqual(list(result.to_dict().items()), [("a", 12), ("b", 9), ("c", 3)])
+
+    def test_aggregate_by_passes_typed_groups(self):
+        """aggregate_by hands each group to the aggregator as a KotList with the element type"""
+        lst = KotList.of_type(int, [1, 2, 3, 4])
+        result = lst.aggregate_by(lambda x: x % 2, lambda g: g.element_type)
+        self.assertEqual(result.to_dict(), {1: int, 0: int})
+        self.assertTrue(KotList().aggregate_by(lambda x: x, len).is_empty())
+
+
+class TestKotListGroupByMutable(unittest.TestCase):
+    def test_group_by_mutable(self):
+        """group_by_mutable returns mutable groups in first-seen order"""
+        from kotcollections import KotMutableList, KotMutableMap
+        result = KotList([1, 2, 3, 4, 5]).group_by_mutable(lambda x: "odd" if x % 2 else "even")
+        self.assertIsInstance(result, KotMutableMap)
+        self.assertEqual(list(result.to_dict()), ["odd", "even"])
+        self.assertIsInstance(result["odd"], KotMutableList)
+        self.assertEqual(result["odd"].to_list(), [1, 3, 5])
+
+    def test_group_by_mutable_groups_can_be_mutated(self):
+        """Groups and the map itself can be extended after grouping"""
+        from kotcollections import KotMutableList
+        result = KotList.of_type(int, [1, 2]).group_by_mutable(lambda x: x % 2)
+        result[0].add(10)
+        result.put(2, KotMutableList([7]))
+        self.assertEqual(result[0].to_list(), [2, 10])
+        with self.assertRaises(TypeError):
+            result[1].add("x")
+
+
+class TestKotListIfEmptyIfNotEmpty(unittest.TestCase):
+    def test_if_empty_accepts_value_or_callable(self):
+        """if_empty accepts either a function or a plain replacement value"""
+        lst = KotList([1])
+        self.assertIs(lst.if_empty(KotList([2])), lst)
+        self.assertEqual(KotList().if_empty(KotList([2])).to_list(), [2])
+        self.assertEqual(KotList().if_empty(lambda: KotList([3])).to_list(), [3])
+
+    def test_if_not_empty(self):
+        """if_not_empty replaces non-empty lists and keeps empty ones"""
+        empty = KotList()
+        self.assertIs(empty.if_not_empty(lambda: KotList([1])), empty)
+        self.assertEqual(KotList([1]).if_not_empty("present"), "present")
+
+
+class TestKotListOrEmpty(unittest.TestCase):
+    def test_or_empty(self):
+        """or_empty maps None to an empty list, keeps KotLists and wraps iterables"""
+        self.assertTrue(KotList.or_empty(None).is_empty())
+        lst = KotList([1])
+        self.assertIs(KotList.or_empty(lst), lst)
+        self.assertEqual(KotList.or_empty((1, 2)).to_list(), [1, 2])
+
+    def test_or_empty_on_mutable_list(self):
+        """or_empty called on KotMutableList returns a mutable list"""
+        from kotcollections import KotMutableList
+        result = KotMutableList.or_empty(None)
+        self.assertIsInstance(result, KotMutableList)
+        result.add(1)
+        self.assertEqual(result.to_list(), [1])
+
+
+class TestKotListJoinTo(unittest.TestCase):
+    def test_join_to_string_io(self):
+        """join_to writes into a StringIO and returns it"""
+        import io
+        buffer = io.StringIO()
+        buffer.write("items: ")
+        result = KotList([1, 2, 3]).join_to(buffer, separator="-", prefix="[", postfix="]")
+        self.assertIs(result, buffer)
+        self.assertEqual(buffer.getvalue(), "items: [1-2-3]")
+
+    def test_join_to_matches_join_to_string(self):
+        """join_to produces the same text as join_to_string, including limit and transform"""
+        import io
+        lst = KotList([1, 2, 3, 4])
+        kwargs = dict(limit=2, truncated="...", transform=lambda x: f"<{x}>")
+        self.assertEqual(lst.join_to(io.StringIO(), **kwargs).getvalue(), lst.join_to_string(**kwargs))
+        self.assertEqual(lst.join_to_string(limit=2), "1, 2...")
+
+    def test_join_to_file(self):
+        """join_to streams into a text file"""
+        import tempfile
+        with tempfile.TemporaryFile("w+") as f:
+            KotList(["a", "b"]).join_to(f)
+            f.seek(0)
+            self.assertEqual(f.read(), "a, b")
+
+
+class TestKotListEqualsNative(unittest.TestCase):
+    def test_equals_native_nested(self):
+        """equals_native compares nested Kot collections against native literals"""
+        lst = KotList([KotList([1, 2]), KotList([3])])
+        self.assertTrue(lst.equals_native([[1, 2], [3]]))
+        self.assertFalse(lst.equals_native([[1, 2], [4]]))
+        self.assertFalse(lst.equals_native([[1, 2]]))
+        self.assertNotEqual(lst, [[1, 2], [3]])
+
+    def test_equals_native_mixed_structures(self):
+        """equals_native unwraps sets and maps nested inside a list"""
+        lst = KotList.of_type(object, [KotSet([1, 2]), KotMap({"a": KotList([1])})])
+        self.assertTrue(lst.equals_native([{2, 1}, {"a": [1]}]))
+        self.assertFalse(lst.equals_native([{1, 2}, {"a": (1,)}]))
+
+
+class TestKotListDeclaredTypeRepr(unittest.TestCase):
+    def test_repr_shows_declared_type(self):
+        """repr includes the element type only when it was declared"""
+        self.assertEqual(repr(KotList.of_type(int, [1, 2])), "KotList[int]([1, 2])")
+        self.assertEqual(repr(KotList[str](["a"])), "KotList[str](['a'])")
+        self.assertEqual(repr(KotList([1, 2])), "KotList([1, 2])")
+
+    def test_repr_follows_declaration_through_conversions(self):
+        """Type-preserving conversions keep the declared-or-inferred distinction"""
+        from kotcollections import KotMutableList
+        self.assertEqual(repr(KotList([1]).to_kot_mutable_list()), "KotMutableList([1])")
+        self.assertEqual(repr(KotList.of_type(int, [1]).to_kot_mutable_list()), "KotMutableList[int]([1])")
+        self.assertEqual(repr(KotList([1, 2]).rotated(1)), "KotList([2, 1])")
+        self.assertEqual(repr(KotMutableList.of_type(int, [1, 2])[:1]), "KotMutableList[int]([1])")
+
+
+class TestKotListIsTypeDeclared(unittest.TestCase):
+    def test_is_type_declared(self):
+        """is_type_declared distinguishes declared element types from inferred ones"""
+        from kotcollections import KotMutableList
+        self.assertTrue(KotList.of_type(int, [1]).is_type_declared())
+        self.assertTrue(KotMutableList.empty_of_type(str).is_type_declared())
+        inferred = KotList([1])
+        self.assertEqual(inferred.element_type, int)
+        self.assertFalse(inferred.is_type_declared())
+        self.assertFalse(KotList().is_type_declared())
+
+    def test_inferred_mutable_list_stays_undeclared(self):
+        """Adding to an untyped mutable list infers a type without declaring it"""
+        from kotcollections import KotMutableList
+        lst = KotMutableList()
+        lst.add(1)
+        self.assertEqual(lst.element_type, int)
+        self.assertFalse(lst.is_type_declared())
+
+
+class TestKotListMapIndexedWithAccumulator(unittest.TestCase):
+    def test_threads_accumulator(self):
+        """map_indexed_with_accumulator passes index, accumulator and element to the transform"""
+        calls = []
+
+        def transform(i, acc, element):
+            calls.append((i, acc, element))
+            return acc + element, f"{i}:{acc + element}"
+
+        result = KotList([1, 2, 3]).map_indexed_with_accumulator(10, transform)
+        self.assertEqual(result.to_list(), ["0:11", "1:13", "2:16"])
+        self.assertEqual(calls, [(0, 10, 1), (1, 11, 2), (2, 13, 3)])
+
+    def test_empty_list(self):
+        """map_indexed_with_accumulator on an empty list never calls the transform"""
+        result = KotList().map_indexed_with_accumulator(0, lambda i, a, e: self.fail("called"))
+        self.assertTrue(result.is_empty())
+
+
+class TestKotListNativeExtractors(unittest.TestCase):
+    def test_extractors(self):
+        """to_int_list, to_float_list and to_str_list return converted native lists"""
+        lst = KotList([1, 2, 3])
+        self.assertEqual(lst.to_int_list(), [1, 2, 3])
+        floats = lst.to_float_list()
+        self.assertEqual(floats, [1.0, 2.0, 3.0])
+        self.assertTrue(all(isinstance(v, float) for v in floats))
+        self.assertEqual(lst.to_str_list(), ["1", "2", "3"])
+        self.assertEqual(KotList(["4", "5"]).to_int_list(), [4, 5])
+        self.assertIsInstance(lst.to_int_list(), list)
+
+    def test_conversion_error_names_index(self):
+        """A failed conversion raises TypeError naming the offending index"""
+        with self.assertRaises(TypeError) as cm:
+            KotList(["1", "x"]).to_int_list()
+        self.assertIn("index 1", str(cm.exception))
+        with self.assertRaises(TypeError):
+            KotList([None]).to_float_list()
+
+    def test_to_int_list_rejects_fractional_values(self):
+        """to_int_list converts integral floats but refuses to truncate fractional ones"""
+        from decimal import Decimal
+        self.assertEqual(KotList([1.0, 2.0]).to_int_list(), [1, 2])
+        self.assertEqual(KotList([Decimal("3")]).to_int_list(), [3])
+        with self.assertRaisesRegex(ValueError, r"index 1 \(1\.9\) is not an integral value"):
+            KotList([1.0, 1.9]).to_int_list()
+        with self.assertRaisesRegex(ValueError, "index 0"):
+            KotList([float("inf")]).to_int_list()
+        with self.assertRaisesRegex(ValueError, "index 0"):
+            KotList([Decimal("0.5")]).to_int_list()
+
+
+class TestKotListContainsAny(unittest.TestCase):
+    def test_contains_any(self):
+        """contains_any is true when any given element is present"""
+        lst = KotList([1, 2, 3])
+        self.assertTrue(lst.contains_any([9, 2]))
+        self.assertFalse(lst.contains_any([8, 9]))
+        self.assertFalse(lst.contains_any([]))
+        self.assertTrue(KotList([[1], [2]]).contains_any([[2]]))
+
+    def test_contains_any_short_circuits(self):
+        """contains_any stops consuming the argument after the first hit"""
+        consumed = []
+
+        def gen():
+            for x in [5, 1, 7, 8]:
+                consumed.append(x)
+                yield x
+
+        self.assertTrue(KotList([1, 2]).contains_any(gen()))
+        self.assertEqual(consumed, [5, 1])
+
+
+class TestKotListConcat(unittest.TestCase):
+    def test_concat(self):
+        """concat joins lists, sets and plain iterables in order"""
+        result = KotList.concat([KotList([1, 2]), [3], iter((4, 5)), KotList()])
+        self.assertEqual(result.to_list(), [1, 2, 3, 4, 5])
+        self.assertTrue(KotList.concat([]).is_empty())
+
+    def test_concat_element_type(self):
+        """concat keeps the element type only when all typed sources agree"""
+        same = KotList.concat([KotList.of_type(int, [1]), KotList.of_type(int, [2])])
+        self.assertEqual(same.element_type, int)
+        self.assertTrue(same.is_type_declared())
+        self.assertFalse(KotList.concat([KotList([1]), KotList([2])]).is_type_declared())
+
+    def test_concat_mutable(self):
+        """concat called on KotMutableList returns a mutable list"""
+        from kotcollections import KotMutableList
+        result = KotMutableList.concat([[1], [2]])
+        self.assertIsInstance(result, KotMutableList)
+
+
+class TestKotListPartitionIndexed(unittest.TestCase):
+    def test_partition_indexed(self):
+        """partition_indexed splits by index-aware predicate, preserving order"""
+        even, odd = KotList(["a", "b", "c", "d", "e"]).partition_indexed(lambda i, _: i % 2 == 0)
+        self.assertEqual(even.to_list(), ["a", "c", "e"])
+        self.assertEqual(odd.to_list(), ["b", "d"])
+
+    def test_partition_indexed_keeps_element_type(self):
+        """Both outputs keep the element type, even when empty"""
+        lst = KotList.of_type(int, [1, 2])
+        matching, non_matching = lst.partition_indexed(lambda i, e: True)
+        self.assertEqual(matching.to_list(), [1, 2])
+        self.assertIs(non_matching.element_type, int)
+
+
+class TestKotListToKotListSharing(unittest.TestCase):
+    def test_to_kot_list_shares_backing_list(self):
+        """to_kot_list on a KotList shares the backing list and keeps the declared type"""
+        original = KotList.of_type(int, [1, 2, 3])
+        copy = original.to_kot_list()
+        self.assertIsNot(copy, original)
+        self.assertIs(copy._elements, original._elements)
+        self.assertEqual(copy, original)
+        self.assertTrue(copy.is_type_declared())
+
+
+class TestKotListOfTypeValidation(unittest.TestCase):
+    def test_of_type_rejects_non_type(self):
+        """of_type raises TypeError when element_type is not a type"""
+        with self.assertRaises(TypeError) as cm:
+            KotList.of_type("int", [1])
+        self.assertIn("KotList.of_type() expects a type", str(cm.exception))
+        with self.assertRaises(TypeError):
+            KotList.of_type(None)
+
+
+class TestKotListReverseIterator(unittest.TestCase):
+    def test_reverse_iterator_walks_backward(self):
+        """reverse_iterator yields the elements from last to first"""
+        self.assertEqual(list(KotList([1, 2, 3]).reverse_iterator()), [3, 2, 1])
+        self.assertEqual(list(KotList().reverse_iterator()), [])
+
+    def test_reverse_iterator_is_lazy(self):
+        """reverse_iterator yields elements on demand so an early break is cheap"""
+        iterator = KotList(range(10_000)).reverse_iterator()
+        self.assertEqual(next(iterator), 9_999)
+        self.assertEqual(next(iterator), 9_998)
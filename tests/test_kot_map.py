@@ -961,5 +961,455 @@ class TestKotMapTypeSpecification(unittest.TestCase):
         self.assertEqual(tag_map.get(immutable_tags2), "JVM language")
 
 
+class TestKotMapEmptyOfTypes(unittest.TestCase):
+    def test_empty_of_types(self):
+        """Test empty_of_types creates an empty map with fixed key and value types."""
+        m = KotMap.empty_of_types(str, int)
+        self.assertTrue(m.is_empty())
+        self.assertEqual(m._key_type, str)
+        self.assertEqual(m._value_type, int)
+
+    def test_empty_of_types_mutable_checks_first_put(self):
+        """Test the first put is type-checked instead of inferring the types."""
+        m = KotMutableMap.empty_of_types(str, int)
+        self.assertIsInstance(m, KotMutableMap)
+        with self.assertRaises(TypeError):
+            m.put(1, 1)
+        with self.assertRaises(TypeError):
+            m.put("a", "1")
+        m.put("a", 1)
+        self.assertEqual(m.get("a"), 1)
+
+
+class TestKotMapMapKeysCollisions(unittest.TestCase):
+    def test_map_keys_last_wins(self):
+        """Colliding transformed keys keep the value of the last entry"""
+        m = KotMap({"a": 1, "B": 2, "b": 3, "A": 4})
+        result = m.map_keys(lambda k, v: k.lower())
+        self.assertEqual(result.size, 2)
+        self.assertEqual(result.get("a"), 4)
+        self.assertEqual(result.get("b"), 3)
+        self.assertEqual(list(result.to_dict()), ["a", "b"])
+
+    def test_map_keys_not_null(self):
+        """map_keys_not_null drops entries whose transformed key is None"""
+        m = KotMap({1: "one", 2: "two", 3: "three"})
+        result = m.map_keys_not_null(lambda k, v: None if k == 2 else k * 10)
+        self.assertEqual(result.to_dict(), {10: "one", 30: "three"})
+        self.assertEqual(m.map_keys_not_none(lambda k, v: None).size, 0)
+        collided = m.map_keys_not_none(lambda k, v: "odd" if k % 2 else None)
+        self.assertEqual(collided.to_dict(), {"odd": "three"})
+
+
+class TestKotMapConstructionDuplicates(unittest.TestCase):
+    def test_duplicate_pairs_last_wins(self):
+        """Duplicate keys in pairs collapse to one entry holding the last value"""
+        m = KotMap([("a", 1), ("b", 2), ("a", 3)])
+        self.assertEqual(m.size, 2)
+        self.assertEqual(len(m), 2)
+        self.assertEqual(m.get("a"), 3)
+        self.assertEqual(list(m.to_dict()), ["a", "b"])
+
+    def test_strict_rejects_duplicates(self):
+        """strict=True raises ValueError on duplicate keys"""
+        with self.assertRaises(ValueError):
+            KotMap([("a", 1), ("a", 2)], strict=True)
+        with self.assertRaises(ValueError):
+            KotMutableMap(iter([("a", 1), ("a", 2)]), strict=True)
+        with self.assertRaises(ValueError):
+            KotMap[str, int]([("a", 1), ("a", 2)], strict=True)
+        m = KotMap([("a", 1), ("b", 2)], strict=True)
+        self.assertEqual(m.to_dict(), {"a": 1, "b": 2})
+        self.assertEqual(KotMutableMap[str, int]({"a": 1}, strict=True).get("a"), 1)
+
+
+class TestKotMapValueLookups(unittest.TestCase):
+    def test_keys_for_value(self):
+        """keys_for_value returns every key mapped to the value"""
+        m = KotMap({"a": 1, "b": 2, "c": 1})
+        self.assertEqual(m.keys_for_value(1).to_list(), ["a", "c"])
+        self.assertEqual(m.keys_for_value(2).to_list(), ["b"])
+        self.assertTrue(m.keys_for_value(3).is_empty())
+        self.assertTrue(m.contains_value(1))
+        self.assertFalse(m.contains_value(3))
+
+    def test_unhashable_values_fall_back(self):
+        """Unhashable values are still found via a linear scan"""
+        m = KotMap({"a": [1, 2], "b": [3], "c": [1, 2]})
+        self.assertTrue(m.contains_value([3]))
+        self.assertFalse(m.contains_value([4]))
+        self.assertEqual(m.keys_for_value([1, 2]).to_list(), ["a", "c"])
+        hashable = KotMap({"a": 1})
+        self.assertFalse(hashable.contains_value([1]))
+        self.assertTrue(hashable.keys_for_value([1]).is_empty())
+
+    def test_mutable_map_sees_updates(self):
+        """Value lookups on a mutable map reflect later mutations"""
+        m = KotMutableMap({"a": 1})
+        self.assertTrue(m.contains_value(1))
+        m.put("b", 2)
+        m.remove("a")
+        self.assertFalse(m.contains_value(1))
+        self.assertEqual(m.keys_for_value(2).to_list(), ["b"])
+
+
+class TestKotMapGetPath(unittest.TestCase):
+    def test_get_path(self):
+        """get_path descends through nested KotMap and dict values"""
+        config = KotMap.of_type(str, object, {
+            "db": KotMap.of_type(str, object, {"host": "localhost", "options": {"timeout": 30}}),
+            "name": "app",
+        })
+        self.assertEqual(config.get_path("db", "host"), "localhost")
+        self.assertEqual(config.get_path("db", "options", "timeout"), 30)
+        self.assertEqual(config.get_path("name"), "app")
+        self.assertIs(config.get_path(), config)
+
+    def test_get_path_missing(self):
+        """get_path returns None at the first miss or non-map value"""
+        config = KotMutableMap.of_type(str, object, {"db": {"host": "localhost"}, "name": "app"})
+        self.assertIsNone(config.get_path("db", "port"))
+        self.assertIsNone(config.get_path("missing", "host"))
+        self.assertIsNone(config.get_path("name", "first"))
+        self.assertIsNone(config.get_path("db", "host", "x"))
+
+
+class TestKotMapFlatMapValues(unittest.TestCase):
+    def test_flat_map_values(self):
+        """flat_map_values concatenates the value collections"""
+        buckets = KotMap({"a": [1, 2], "b": [3], "c": []})
+        self.assertEqual(buckets.flat_map_values().to_list(), [1, 2, 3])
+        nested = KotMutableMap({"x": KotList([1]), "y": KotList([2, 3])})
+        self.assertEqual(nested.flat_map_values().to_list(), [1, 2, 3])
+        self.assertTrue(KotMap().flat_map_values().is_empty())
+
+    def test_flat_map_values_with_transform(self):
+        """flat_map_values applies the transform to each entry"""
+        buckets = KotMap({"a": [1, 2], "b": [3]})
+        self.assertEqual(buckets.flat_map_values(lambda k, v: [k] * len(v)).to_list(), ["a", "a", "b"])
+
+
+class TestKotMapContainsEntry(unittest.TestCase):
+    def test_contains_entry(self):
+        """contains_entry checks for an exact key-value mapping"""
+        m = KotMutableMap({"a": 1, "b": None})
+        self.assertTrue(m.contains_entry("a", 1))
+        self.assertFalse(m.contains_entry("a", 2))
+        self.assertTrue(m.contains_entry("b", None))
+        self.assertFalse(m.contains_entry("c", None))
+        self.assertFalse(KotMap().contains_entry("a", 1))
+
+
+class TestKotMapEmptyRepr(unittest.TestCase):
+    def test_empty_reprs_are_distinct(self):
+        """Empty sets, maps and lists have distinct, unambiguous reprs"""
+        from kotcollections.kot_set import KotSet
+        from kotcollections.kot_mutable_set import KotMutableSet
+        reprs = [
+            repr(KotList()), repr(KotMutableList()),
+            repr(KotSet()), repr(KotMutableSet()),
+            repr(KotMap()), repr(KotMutableMap()),
+        ]
+        self.assertEqual(reprs, [
+            "KotList([])", "KotMutableList([])",
+            "KotSet([])", "KotMutableSet([])",
+            "KotMap({})", "KotMutableMap({})",
+        ])
+        self.assertEqual(len(set(reprs)), 6)
+
+    def test_mutable_map_repr(self):
+        """KotMutableMap reprs with its own class name"""
+        self.assertEqual(repr(KotMutableMap({"a": 1})), "KotMutableMap({'a': 1})")
+
+
+class TestKotMapFormat(unittest.TestCase):
+    def test_format(self):
+        """Format specs join the key=value entries; an empty spec falls back to str()"""
+        m = KotMap({"a": 1, "b": 2})
+        self.assertEqual(f"{m:; }", "a=1; b=2")
+        self.assertEqual(f"{m}", str(m))
+        self.assertEqual(f"{KotMutableMap({'x': 0}):,}", "x=0")
+
+
+class TestKotMapWithIndex(unittest.TestCase):
+    def test_with_index(self):
+        """with_index returns a KotList of (index, (key, value)) in insertion order"""
+        m = KotMap({"b": 2, "a": 1})
+        indexed = m.with_index()
+        self.assertIsInstance(indexed, KotList)
+        self.assertEqual(indexed.to_list(), [(0, ("b", 2)), (1, ("a", 1))])
+        for i, (k, v) in KotMutableMap({"x": 0}).with_index():
+            self.assertEqual((i, k, v), (0, "x", 0))
+        self.assertEqual(list(KotMap().with_index()), [])
+
+
+class TestKotMapMergeMaps(unittest.TestCase):
+    def test_merge_maps_last_wins(self):
+        """Later maps override earlier ones; keys keep first-appearance order"""
+        merged = KotMap.merge_maps([{"a": 1, "b": 2}, KotMap({"b": 3, "c": 4}), {"a": 5}])
+        self.assertIsInstance(merged, KotMap)
+        self.assertEqual(merged.to_dict(), {"a": 5, "b": 3, "c": 4})
+        self.assertEqual(list(merged.to_dict()), ["a", "b", "c"])
+
+    def test_merge_maps_with_resolver(self):
+        """The resolver receives the key, existing and new values"""
+        calls = []
+
+        def resolver(key, existing, new):
+            calls.append((key, existing, new))
+            return existing + new
+
+        merged = KotMap.merge_maps([{"a": 1}, {"a": 2, "b": 1}, {"a": 3}], resolver)
+        self.assertEqual(merged.to_dict(), {"a": 6, "b": 1})
+        self.assertEqual(calls, [("a", 1, 2), ("a", 3, 3)])
+
+    def test_merge_maps_mutable(self):
+        """KotMutableMap.merge_maps returns a mutable map"""
+        merged = KotMutableMap.merge_maps([{"a": 1}, {"b": 2}])
+        self.assertIsInstance(merged, KotMutableMap)
+        merged.put("c", 3)
+        self.assertEqual(merged.size, 3)
+        self.assertTrue(KotMap.merge_maps([]).is_empty())
+
+
+class TestKotMapOrderedViews(unittest.TestCase):
+    def test_keys_list(self):
+        """keys_list returns the keys in insertion order"""
+        m = KotMap({"b": 1, "a": 2, "c": 3})
+        self.assertEqual(m.keys_list().to_list(), ["b", "a", "c"])
+        self.assertEqual(m.keys_list().element_type, str)
+        self.assertTrue(KotMap().keys_list().is_empty())
+
+    def test_entries_list(self):
+        """entries_list returns the entries in insertion order"""
+        m = KotMutableMap({"b": 1, "a": 2})
+        self.assertEqual(m.entries_list().to_list(), [("b", 1), ("a", 2)])
+
+
+class TestKotMapToOrderedDict(unittest.TestCase):
+    def test_to_ordered_dict(self):
+        """to_ordered_dict keeps insertion order and supports OrderedDict operations"""
+        from collections import OrderedDict
+        m = KotMutableMap({"b": 1, "a": 2})
+        m.put("c", 3)
+        od = m.to_ordered_dict()
+        self.assertIsInstance(od, OrderedDict)
+        self.assertEqual(list(od.items()), [("b", 1), ("a", 2), ("c", 3)])
+        od.move_to_end("b")
+        self.assertEqual(list(od), ["a", "c", "b"])
+        self.assertEqual(list(m.to_dict()), ["b", "a", "c"])
+        self.assertEqual(KotMap().to_ordered_dict(), OrderedDict())
+
+
+class TestKotMapValuesAt(unittest.TestCase):
+    def test_values_at_in_key_order(self):
+        """values_at returns values in the order of the requested keys"""
+        m = KotMap({"a": 1, "b": 2, "c": 3})
+        self.assertEqual(m.values_at(["c", "a", "c"]).to_list(), [3, 1, 3])
+        self.assertEqual(m.values_at([]).to_list(), [])
+
+    def test_values_at_missing_keys(self):
+        """values_at substitutes None for missing keys unless strict"""
+        m = KotMutableMap({"a": 1, "b": 2})
+        self.assertEqual(m.values_at(["a", "x", "b"]).to_list(), [1, None, 2])
+        self.assertEqual(m.values_at(["x"]).to_list(), [None])
+        with self.assertRaises(KeyError):
+            m.values_at(["a", "x"], strict=True)
+
+    def test_values_at_keeps_value_type(self):
+        """values_at keeps the map's value type when every key is present"""
+        m = KotMap.of_type(str, int, {"a": 1, "b": 2})
+        self.assertEqual(m.values_at(["b"]).element_type, int)
+
+    def test_values_at_single_missing_key(self):
+        """A single missing key gives the same KotList[object] as a missing key among others"""
+        m = KotMap({"a": 1})
+        single = m.values_at(["x"])
+        self.assertEqual(single.to_list(), [None])
+        self.assertEqual(single.element_type, object)
+        self.assertEqual(m.values_at(["a", "x"]).element_type, object)
+
+    def test_values_at_missing_key_with_declared_value_type(self):
+        """A missing key on a map with a declared value type gives KotList[object]"""
+        m = KotMap.of_type(str, int, {"a": 1})
+        self.assertEqual(m.values_at(["x"]).element_type, object)
+        result = m.values_at(["a", "x"])
+        self.assertEqual(result.to_list(), [1, None])
+        self.assertEqual(result.element_type, object)
+
+
+class TestKotMapSortedEntries(unittest.TestCase):
+    def test_sorted_by_key_and_value(self):
+        """sorted_entries orders entries by key by default, or by value"""
+        m = KotMap({"b": 1, "c": 3, "a": 2})
+        self.assertEqual(m.sorted_entries().to_list(), [("a", 2), ("b", 1), ("c", 3)])
+        self.assertEqual(m.sorted_entries(by="value").to_list(), [("b", 1), ("a", 2), ("c", 3)])
+
+    def test_sorted_with_comparator(self):
+        """sorted_entries applies the comparator to the selected component"""
+        m = KotMap({"b": 1, "c": 3, "a": 2})
+        descending = lambda x, y: (y > x) - (y < x)
+        self.assertEqual([k for k, _ in m.sorted_entries(comparator=descending)], ["c", "b", "a"])
+        self.assertEqual([v for _, v in m.sorted_entries(by="value", comparator=descending)], [3, 2, 1])
+
+    def test_invalid_by(self):
+        """sorted_entries rejects an unknown by argument"""
+        with self.assertRaises(ValueError):
+            KotMap({"a": 1}).sorted_entries(by="size")
+
+
+class TestKotMapRequireInstance(unittest.TestCase):
+    def test_require_keys_instance(self):
+        """require_keys_instance returns self or raises TypeError for a mismatched key"""
+        m = KotMap({"a": 1, "b": 2})
+        self.assertIs(m.require_keys_instance(str), m)
+        with self.assertRaises(TypeError):
+            m.require_keys_instance(int)
+
+    def test_require_values_instance(self):
+        """require_values_instance accepts subclasses and rejects mismatched values"""
+        m = KotMutableMap({"a": True, "b": False})
+        self.assertIs(m.require_values_instance(int), m)
+        with self.assertRaises(TypeError):
+            m.require_values_instance(str)
+
+    def test_empty_map_passes(self):
+        """An empty map satisfies any instance requirement"""
+        m = KotMap()
+        self.assertIs(m.require_keys_instance(int).require_values_instance(str), m)
+
+
+class TestKotMapIfEmptyIfNotEmpty(unittest.TestCase):
+    def test_if_empty_and_if_not_empty(self):
+        """if_empty and if_not_empty pick between the map and the default value"""
+        m = KotMap({"a": 1})
+        self.assertIs(m.if_empty({}), m)
+        self.assertEqual(KotMap().if_empty(lambda: KotMap({"b": 2})).to_dict(), {"b": 2})
+        self.assertEqual(KotMap().if_empty({"c": 3}), {"c": 3})
+        self.assertEqual(m.if_not_empty(lambda: "non-empty"), "non-empty")
+        self.assertTrue(KotMap().if_not_empty("x").is_empty())
+
+
+class TestKotMapOrEmpty(unittest.TestCase):
+    def test_or_empty(self):
+        """or_empty maps None to an empty map, keeps KotMaps and wraps dicts or pairs"""
+        self.assertTrue(KotMap.or_empty(None).is_empty())
+        m = KotMap({"a": 1})
+        self.assertIs(KotMap.or_empty(m), m)
+        self.assertEqual(KotMap.or_empty({"b": 2}).to_dict(), {"b": 2})
+        self.assertEqual(KotMap.or_empty([("c", 3)]).to_dict(), {"c": 3})
+
+
+class TestKotMapEqualsNative(unittest.TestCase):
+    def test_equals_native(self):
+        """equals_native deep-compares map values after unwrapping Kot collections"""
+        m = KotMutableMap({"a": KotList([1, 2]), "b": KotList([3])})
+        self.assertTrue(m.equals_native({"b": [3], "a": [1, 2]}))
+        self.assertFalse(m.equals_native({"a": [1, 2]}))
+        self.assertFalse(m.equals_native({"a": [1, 2], "b": [4]}))
+        self.assertTrue(KotMap({"x": KotMap({"y": 1})}).equals_native({"x": {"y": 1}}))
+
+
+class TestKotMapDeclaredTypeRepr(unittest.TestCase):
+    def test_repr_shows_declared_types(self):
+        """repr includes the key and value types only when they were declared"""
+        self.assertEqual(repr(KotMap.of_type(str, int, {"a": 1})), "KotMap[str, int]({'a': 1})")
+        self.assertEqual(repr(KotMap({"a": 1})), "KotMap({'a': 1})")
+        self.assertEqual(repr(KotMutableMap.of_type(str, int, {"a": 1})), "KotMutableMap[str, int]({'a': 1})")
+        self.assertEqual(repr(KotMap({"a": 1}).to_kot_map()), "KotMap({'a': 1})")
+
+    def test_repr_after_clearing_declared_map(self):
+        """A declared mutable map keeps its declared types, and their repr, after clear()"""
+        m = KotMutableMap.of_type(str, int, {"a": 1})
+        m.clear()
+        self.assertEqual(repr(m), "KotMutableMap[str, int]({})")
+
+
+class TestKotMapIsTypeDeclared(unittest.TestCase):
+    def test_key_and_value_type_declared(self):
+        """Key and value declarations are tracked independently"""
+        from typing import TypeVar
+        V = TypeVar('V')
+        declared = KotMap.of_type(str, int, {"a": 1})
+        self.assertTrue(declared.is_key_type_declared())
+        self.assertTrue(declared.is_value_type_declared())
+        key_only = KotMap[str, V]({"a": 1})
+        self.assertTrue(key_only.is_key_type_declared())
+        self.assertFalse(key_only.is_value_type_declared())
+
+    def test_inferred_types_are_not_declared(self):
+        """Types inferred from the first entry are not reported as declared"""
+        m = KotMutableMap()
+        m.put("a", 1)
+        self.assertFalse(m.is_key_type_declared())
+        self.assertFalse(m.is_value_type_declared())
+        self.assertFalse(KotMap({"a": 1}).to_kot_mutable_map().is_key_type_declared())
+
+
+class TestKotMapEntriesIterator(unittest.TestCase):
+    def test_entries_iterator_yields_pairs_in_order(self):
+        """entries_iterator yields (key, value) tuples in insertion order"""
+        kot_map = KotMap({"b": 2, "a": 1})
+        self.assertEqual(list(kot_map.entries_iterator()), [("b", 2), ("a", 1)])
+        self.assertEqual(list(kot_map), ["b", "a"])
+
+    def test_entries_iterator_empty(self):
+        """entries_iterator on an empty map yields nothing"""
+        self.assertEqual(list(KotMap().entries_iterator()), [])
+
+
+class TestKotMapEntryAt(unittest.TestCase):
+    def test_entry_at_returns_pair_in_insertion_order(self):
+        """entry_at returns the (key, value) pair at the given position"""
+        kot_map = KotMap({"a": 1, "b": 2, "c": 3})
+        self.assertEqual(kot_map.entry_at(0), ("a", 1))
+        key, value = kot_map.entry_at(2)
+        self.assertEqual((key, value), ("c", 3))
+
+    def test_entry_at_out_of_bounds(self):
+        """entry_at raises IndexError for negative or too large indices"""
+        kot_map = KotMap({"a": 1})
+        with self.assertRaises(IndexError):
+            kot_map.entry_at(1)
+        with self.assertRaises(IndexError):
+            kot_map.entry_at(-1)
+
+    def test_entry_at_rejects_non_integer_index(self):
+        """entry_at validates its index like the other positional accessors"""
+        kot_map = KotMap({"a": 1, "b": 2})
+        with self.assertRaises(TypeError) as cm:
+            kot_map.entry_at(1.0)
+        self.assertIn("Index must be an integer", str(cm.exception))
+        with self.assertRaises(TypeError):
+            kot_map.entry_at("0")
+
+    def test_get_value_missing_key_raises_key_error(self):
+        """get_value raises KeyError for a missing key"""
+        with self.assertRaises(KeyError):
+            KotMap({"a": 1}).get_value("b")
+
+
+class TestKotMapOfKwargs(unittest.TestCase):
+    def test_of_kwargs_builds_string_keyed_map(self):
+        """of_kwargs builds a map from keyword arguments in the order given"""
+        kot_map = KotMap.of_kwargs(b=2, a=1)
+        self.assertEqual(list(kot_map.entries_iterator()), [("b", 2), ("a", 1)])
+        self.assertEqual(kot_map._key_type, str)
+        self.assertEqual(kot_map._value_type, int)
+
+    def test_of_kwargs_empty(self):
+        """of_kwargs without arguments returns an empty map"""
+        self.assertTrue(KotMap.of_kwargs().is_empty())
+
+
+class TestKotMapOfTypeValidation(unittest.TestCase):
+    def test_of_type_rejects_non_type(self):
+        """of_type raises TypeError when the key or value type is not a type"""
+        with self.assertRaises(TypeError):
+            KotMap.of_type(str, "int")
+        with self.assertRaises(TypeError):
+            KotMutableMap.of_type(None, int)
+
+
 if __name__ == '__main__':
     unittest.main()
\ No newline at end of file
@@ -1,7 +1,7 @@
 import random
 import unittest
 
-from kotcollections import KotMutableList, KotMap
+from kotcollections import KotList, KotMutableList, KotMap, KotSet
 
 
 class TestKotMutableListBasics(unittest.TestCase):
@@ -681,6 +681,16 @@ class TestKotMutableListNewAPIs(unittest.TestCase):
         with self.assertRaises(TypeError):
             lst.replace_all(lambda x: str(x))  # Converting int to str should fail
 
+    def test_get_range(self):
+        """Test get_range returns a read-only copy of the stepped range"""
+        lst = KotMutableList([1, 2, 3, 4, 5])
+        result = lst.get_range(0, 5, 2)
+        self.assertEqual(result.to_list(), [1, 3, 5])
+        self.assertNotIsInstance(result, KotMutableList)
+
+        lst.set(0, 10)
+        self.assertEqual(result.to_list(), [1, 3, 5])
+
 
 class TestKotMutableListIterator(unittest.TestCase):
     def test_list_iterator_creation(self):
@@ -908,3 +918,449 @@ class TestKotMutableListSubList(unittest.TestCase):
         del sub[1]  # Remove 3
         self.assertEqual(sub.to_list(), [2, 4])
         self.assertEqual(lst.to_list(), [1, 2, 4, 5])
+
+
+class TestKotMutableListTypedChunks(unittest.TestCase):
+    def test_chunked_preserves_element_type(self):
+        """Test chunked produces inner lists typed like the source list"""
+        ml = KotMutableList.of_type(int, [1, 2, 3, 4])
+        self.assertIs(ml.chunked(2)[0].element_type, int)
+
+    def test_windowed_preserves_declared_parent_type(self):
+        """Test windowed keeps a declared parent type instead of inferring the subclass"""
+        class Animal:
+            pass
+
+        class Dog(Animal):
+            pass
+
+        ml = KotMutableList.of_type(Animal, [Dog(), Dog(), Dog()])
+        windows = ml.windowed(2)
+        self.assertEqual(windows.size, 2)
+        for window in windows:
+            self.assertIs(window.element_type, Animal)
+
+        chunk_types = ml.chunked_transform(2, lambda chunk: chunk.element_type)
+        self.assertEqual(chunk_types.to_list(), [Animal, Animal])
+
+
+class TestKotMutableListRepr(unittest.TestCase):
+    def test_repr(self):
+        """KotMutableList reprs with its own class name"""
+        self.assertEqual(repr(KotMutableList([1, 2])), "KotMutableList([1, 2])")
+        self.assertEqual(repr(KotMutableList()), "KotMutableList([])")
+        self.assertEqual(str(KotMutableList([1, 2])), "[1, 2]")
+
+
+class TestKotMutableListComparatorErrors(unittest.TestCase):
+    def test_sort_with_reports_comparator_errors(self):
+        """sort_with lets comparator exceptions propagate and rejects non-number results"""
+        lst = KotMutableList([3, 1, 2])
+        with self.assertRaises(ZeroDivisionError):
+            lst.sort_with(lambda a, b: 1 // 0)
+        with self.assertRaises(TypeError):
+            lst.sort_with(lambda a, b: "a")
+        lst.sort_with(lambda a, b: a - b)
+        self.assertEqual(lst.to_list(), [1, 2, 3])
+
+
+class TestKotMutableListFindOrElse(unittest.TestCase):
+    def test_find_or_else(self):
+        """find_or_else and find_last_or_else work on mutable lists"""
+        lst = KotMutableList([0, 1, 0])
+        self.assertEqual(lst.find_or_else(lambda x: x == 0, lambda: 9), 0)
+        lst.clear()
+        self.assertEqual(lst.find_last_or_else(lambda x: x == 0, lambda: 9), 9)
+
+
+class TestKotMutableListSnapshot(unittest.TestCase):
+    def test_snapshot(self):
+        """snapshot returns an immutable copy that can be iterated while mutating"""
+        lst = KotMutableList([1, 2, 3])
+        for x in lst.snapshot():
+            lst.add(x * 10)
+        self.assertEqual(lst.to_list(), [1, 2, 3, 10, 20, 30])
+        snap = lst.snapshot()
+        self.assertNotIsInstance(snap, KotMutableList)
+        lst.clear()
+        self.assertEqual(snap.size, 6)
+        self.assertEqual(snap.element_type, int)
+
+    def test_snapshot_of_sub_list_view(self):
+        """snapshot on a sub_list view copies only the view's elements"""
+        parent = KotMutableList([1, 2, 3, 4])
+        view = parent.sub_list(1, 3)
+        snap = view.snapshot()
+        view.add(9)
+        self.assertEqual(snap.to_list(), [2, 3])
+        self.assertNotIsInstance(snap, KotMutableList)
+        self.assertEqual(snap.element_type, int)
+
+
+class TestKotMutableListContains(unittest.TestCase):
+    def test_contains_sees_mutations(self):
+        """Membership checks on a mutable list reflect later mutations"""
+        lst = KotMutableList([1, 2])
+        self.assertIn(1, lst)
+        lst.remove(1)
+        lst.add(3)
+        self.assertNotIn(1, lst)
+        self.assertIn(3, lst)
+        self.assertIn(3, lst.as_reversed())
+        self.assertNotIn(2, lst.sub_list(1, 2))
+
+
+class TestKotMutableListSlicing(unittest.TestCase):
+    def test_slice_returns_mutable_copy(self):
+        """Slicing returns a new, independent KotMutableList"""
+        lst = KotMutableList([1, 2, 3, 4, 5])
+        part = lst[1:3]
+        self.assertIsInstance(part, KotMutableList)
+        self.assertEqual(part.to_list(), [2, 3])
+        part.add(9)
+        self.assertEqual(lst.to_list(), [1, 2, 3, 4, 5])
+        self.assertEqual(lst[::2].to_list(), [1, 3, 5])
+        self.assertEqual(lst[::-1].to_list(), [5, 4, 3, 2, 1])
+        self.assertTrue(lst[10:].is_empty())
+        self.assertEqual(lst[0], 1)
+
+    def test_slice_preserves_type(self):
+        """The slice keeps and enforces the source element type"""
+        part = KotMutableList.of_type(int, [1, 2, 3])[:2]
+        self.assertEqual(part.element_type, int)
+        with self.assertRaises(TypeError):
+            part.add("x")
+
+    def test_slice_of_sub_list_view(self):
+        """Slicing a sub_list view returns an independent KotMutableList of the view's elements"""
+        lst = KotMutableList.of_type(int, [1, 2, 3, 4])
+        part = lst.sub_list(1, 4)[0:2]
+        self.assertIsInstance(part, KotMutableList)
+        self.assertEqual(part.to_list(), [2, 3])
+        self.assertEqual(part.element_type, int)
+        part.add(9)
+        self.assertEqual(lst.to_list(), [1, 2, 3, 4])
+        self.assertEqual(lst.sub_list(1, 4)[::-1].to_list(), [4, 3, 2])
+
+
+class TestKotMutableListSetAll(unittest.TestCase):
+    def test_set_all(self):
+        """set_all overwrites a contiguous region and returns the count written"""
+        lst = KotMutableList([0, 0, 0, 0, 0])
+        self.assertEqual(lst.set_all(1, [7, 8]), 2)
+        self.assertEqual(lst.to_list(), [0, 7, 8, 0, 0])
+        self.assertEqual(lst.set_all(3, iter([1, 2])), 2)
+        self.assertEqual(lst.to_list(), [0, 7, 8, 1, 2])
+        self.assertEqual(lst.set_all(5, []), 0)
+
+    def test_set_all_out_of_bounds(self):
+        """Writing past the end raises IndexError and leaves the list unchanged"""
+        lst = KotMutableList([1, 2, 3])
+        with self.assertRaises(IndexError):
+            lst.set_all(2, [9, 9])
+        with self.assertRaises(IndexError):
+            lst.set_all(-1, [9])
+        self.assertEqual(lst.to_list(), [1, 2, 3])
+
+    def test_set_all_type_checks_first(self):
+        """A type error on any element leaves the list unchanged"""
+        lst = KotMutableList([1, 2, 3])
+        with self.assertRaises(TypeError):
+            lst.set_all(0, [9, "x"])
+        self.assertEqual(lst.to_list(), [1, 2, 3])
+
+    def test_set_all_on_sub_list(self):
+        """set_all on a sub-list view writes through to the parent"""
+        lst = KotMutableList([1, 2, 3, 4])
+        lst.sub_list(1, 3).set_all(0, [20, 30])
+        self.assertEqual(lst.to_list(), [1, 20, 30, 4])
+
+
+class TestKotMutableListIndexValidation(unittest.TestCase):
+    def test_non_integer_indices_rejected(self):
+        """Mutation methods reject non-integer indices with a clear TypeError"""
+        lst = KotMutableList([1, 2, 3])
+        calls = [
+            lambda: lst.set(0.0, 1),
+            lambda: lst.add_at("0", 1),
+            lambda: lst.add_all_at(1.0, [1]),
+            lambda: lst.remove_at(None),
+            lambda: lst.set_all(0.5, [1]),
+            lambda: lst.sub_list(0, "1"),
+        ]
+        for call in calls:
+            with self.assertRaises(TypeError):
+                call()
+        self.assertEqual(lst.to_list(), [1, 2, 3])
+        with self.assertRaises(IndexError):
+            lst.set(-1, 5)
+
+
+class TestKotMutableListRotate(unittest.TestCase):
+    def test_rotate_in_place(self):
+        """rotate mutates the list and returns None"""
+        lst = KotMutableList([1, 2, 3, 4, 5])
+        self.assertIsNone(lst.rotate(2))
+        self.assertEqual(lst.to_list(), [4, 5, 1, 2, 3])
+        lst.rotate(-7)
+        self.assertEqual(lst.to_list(), [1, 2, 3, 4, 5])
+
+    def test_rotate_sub_list_view(self):
+        """rotate on a sub-list view writes through to the parent"""
+        lst = KotMutableList([1, 2, 3, 4, 5])
+        lst.sub_list(1, 4).rotate(1)
+        self.assertEqual(lst.to_list(), [1, 4, 2, 3, 5])
+
+    def test_rotate_rejects_non_integer_distance(self):
+        """rotate raises a rotation-specific TypeError and leaves the list unchanged"""
+        lst = KotMutableList([1, 2, 3])
+        with self.assertRaisesRegex(TypeError, "Rotation distance must be an integer, got 'float'"):
+            lst.rotate(1.0)
+        self.assertEqual(lst.to_list(), [1, 2, 3])
+
+
+class TestKotMutableListTrailingWhile(unittest.TestCase):
+    def test_take_last_while_does_not_mutate(self):
+        """take_last_while on a mutable list returns a read-only KotList and leaves the receiver intact"""
+        lst = KotMutableList([5, 1, 2, 7, 8])
+        result = lst.take_last_while(lambda x: x > 4)
+        self.assertEqual(result.to_list(), [7, 8])
+        self.assertNotIsInstance(result, KotMutableList)
+        self.assertEqual(lst.to_list(), [5, 1, 2, 7, 8])
+
+    def test_drop_last_while_does_not_mutate(self):
+        """drop_last_while on a mutable list scans from the end and leaves the receiver intact"""
+        lst = KotMutableList([5, 1, 2, 7, 8])
+        result = lst.drop_last_while(lambda x: x > 4)
+        self.assertEqual(result.to_list(), [5, 1, 2])
+        self.assertNotIsInstance(result, KotMutableList)
+        self.assertEqual(lst.drop_last_while(lambda x: True).to_list(), [])
+        self.assertEqual(lst.to_list(), [5, 1, 2, 7, 8])
+
+    def test_trailing_while_on_views(self):
+        """take_last_while and drop_last_while see the elements of mutable list views"""
+        lst = KotMutableList([1, 2, 3, 4])
+        self.assertEqual(lst.as_reversed().take_last_while(lambda x: x < 3).to_list(), [2, 1])
+        self.assertEqual(lst.sub_list(0, 3).drop_last_while(lambda x: x > 1).to_list(), [1])
+
+
+class TestKotMutableListSortByWithTiebreak(unittest.TestCase):
+    def test_sort_by_with_tiebreak(self):
+        """sort_by_with_tiebreak orders by the primary key, then the secondary key"""
+        lst = KotMutableList(["bb", "a", "ccc", "ab", "b"])
+        lst.sort_by_with_tiebreak(len, lambda s: s)
+        self.assertEqual(lst.to_list(), ["a", "b", "ab", "bb", "ccc"])
+
+    def test_selectors_called_once_per_element(self):
+        """Each selector runs exactly once per element"""
+        calls = []
+
+        def primary(x):
+            calls.append(x)
+            return x % 3
+
+        lst = KotMutableList([5, 3, 4, 1, 2, 6])
+        lst.sort_by_with_tiebreak(primary, lambda x: -x)
+        self.assertEqual(lst.to_list(), [6, 3, 4, 1, 5, 2])
+        self.assertEqual(sorted(calls), [1, 2, 3, 4, 5, 6])
+
+    def test_sort_sub_list_view(self):
+        """Sorting a sub-list view writes the new order back to the parent"""
+        lst = KotMutableList([9, 3, 2, 1, 0])
+        lst.sub_list(1, 4).sort_by_with_tiebreak(lambda x: x, lambda x: 0)
+        self.assertEqual(lst.to_list(), [9, 1, 2, 3, 0])
+
+
+class TestKotMutableListToKotListCopy(unittest.TestCase):
+    def test_to_kot_list_is_independent_of_later_mutations(self):
+        """to_kot_list on a KotMutableList copies, so later mutations don't leak"""
+        mutable = KotMutableList([1, 2])
+        snapshot = mutable.to_kot_list()
+        mutable.add(3)
+        mutable[0] = 10
+        self.assertEqual(snapshot.to_list(), [1, 2])
+        self.assertNotIsInstance(snapshot, KotMutableList)
+
+
+class TestKotMutableListInsertSorted(unittest.TestCase):
+    def test_insert_sorted_natural_order(self):
+        """insert_sorted keeps the list in natural order and returns the insertion index"""
+        lst = KotMutableList([1, 3, 5])
+        self.assertEqual(lst.insert_sorted(4), 2)
+        self.assertEqual(lst.insert_sorted(0), 0)
+        self.assertEqual(lst.insert_sorted(9), 5)
+        self.assertEqual(lst.to_list(), [0, 1, 3, 4, 5, 9])
+
+    def test_insert_sorted_with_comparator(self):
+        """insert_sorted follows the given comparator and places ties after equal elements"""
+        lst = KotMutableList(["a", "bb", "ccc"])
+        index = lst.insert_sorted("xx", lambda a, b: len(a) - len(b))
+        self.assertEqual(index, 2)
+        self.assertEqual(lst.to_list(), ["a", "bb", "xx", "ccc"])
+
+    def test_insert_sorted_into_empty_list(self):
+        """insert_sorted into an empty list inserts at index 0"""
+        lst = KotMutableList()
+        self.assertEqual(lst.insert_sorted(7), 0)
+        self.assertEqual(lst.to_list(), [7])
+
+    def test_insert_sorted_checks_type(self):
+        """insert_sorted rejects elements of the wrong type"""
+        lst = KotMutableList([1, 2])
+        with self.assertRaises(TypeError):
+            lst.insert_sorted("x")
+        self.assertEqual(lst.to_list(), [1, 2])
+
+
+class TestKotMutableListStackQueueAliases(unittest.TestCase):
+    def test_push_pop_and_peek_as_stack(self):
+        """push, peek_last and pop behave like a stack"""
+        stack = KotMutableList()
+        self.assertTrue(stack.push(1))
+        self.assertTrue(stack.push(2))
+        self.assertEqual(stack.peek_last(), 2)
+        self.assertEqual(stack.pop(), 2)
+        self.assertEqual(stack.pop(), 1)
+        with self.assertRaises(IndexError):
+            stack.pop()
+
+    def test_peek_first_does_not_remove(self):
+        """peek_first returns the head of a queue without removing it"""
+        queue = KotMutableList([1, 2, 3])
+        self.assertEqual(queue.peek_first(), 1)
+        self.assertEqual(queue.remove_first(), 1)
+        self.assertEqual(queue.peek_first(), 2)
+        self.assertEqual(queue.size, 2)
+
+    def test_peek_on_empty_list(self):
+        """peek_* raise on an empty list while the _or_null/_or_none variants return None"""
+        empty = KotMutableList()
+        with self.assertRaises(IndexError):
+            empty.peek_first()
+        with self.assertRaises(IndexError):
+            empty.peek_last()
+        self.assertIsNone(empty.peek_first_or_null())
+        self.assertIsNone(empty.peek_first_or_none())
+        self.assertIsNone(empty.peek_last_or_null())
+        self.assertIsNone(empty.peek_last_or_none())
+
+
+class TestKotMutableListDrain(unittest.TestCase):
+    def test_drain_returns_elements_and_clears(self):
+        """drain returns the prior elements as a read-only list and empties the list"""
+        buffer = KotMutableList([1, 2, 3])
+        drained = buffer.drain()
+        self.assertEqual(drained.to_list(), [1, 2, 3])
+        self.assertNotIsInstance(drained, KotMutableList)
+        self.assertTrue(buffer.is_empty())
+        buffer.add(4)
+        self.assertEqual(drained.to_list(), [1, 2, 3])
+
+    def test_drain_preserves_element_type(self):
+        """drain keeps the declared element type on the returned list"""
+        drained = KotMutableList.of_type(int, [1]).drain()
+        self.assertTrue(drained.is_type_declared())
+        self.assertEqual(drained.element_type, int)
+
+    def test_drain_keeps_receiver_type(self):
+        """A drained typed list still rejects elements of another type"""
+        buffer = KotMutableList.of_type(int, [1])
+        buffer.drain()
+        with self.assertRaises(TypeError):
+            buffer.add("x")
+        self.assertEqual(repr(buffer), "KotMutableList[int]([])")
+
+    def test_drain_sub_list_view(self):
+        """drain on a sub_list view removes only the view's range from the parent"""
+        parent = KotMutableList([1, 2, 3, 4])
+        view = parent.sub_list(1, 3)
+        self.assertEqual(view.drain().to_list(), [2, 3])
+        self.assertTrue(view.is_empty())
+        self.assertEqual(parent.to_list(), [1, 4])
+
+
+class TestKotMutableListAddAllFromKotCollections(unittest.TestCase):
+    def test_add_all_from_kot_list(self):
+        """add_all appends the elements of a KotList source"""
+        target = KotMutableList([1, 2])
+        self.assertTrue(target.add_all(KotList([3, 4])))
+        self.assertFalse(target.add_all(KotList()))
+        self.assertEqual(target.to_list(), [1, 2, 3, 4])
+
+    def test_add_all_at_from_kot_list_and_set(self):
+        """add_all_at inserts the elements of a KotList or KotSet at the given index"""
+        target = KotMutableList([1, 5])
+        self.assertTrue(target.add_all_at(1, KotList([2, 3])))
+        self.assertTrue(target.add_all_at(3, KotSet([4])))
+        self.assertEqual(target.to_list(), [1, 2, 3, 4, 5])
+
+    def test_add_all_from_subclass_typed_source(self):
+        """A source whose element type is a subclass of the target's is accepted"""
+        target = KotMutableList.of_type(int, [1])
+        target.add_all(KotList([True, False]))
+        self.assertEqual(target.to_list(), [1, True, False])
+
+    def test_add_all_from_kot_list_still_checks_type(self):
+        """Mismatched Kot sources are rejected and leave the list unchanged"""
+        target = KotMutableList([1, 2])
+        with self.assertRaises(TypeError):
+            target.add_all(KotList(["a"]))
+        with self.assertRaises(TypeError):
+            target.add_all_at(0, KotSet(["b"]))
+        self.assertEqual(target.to_list(), [1, 2])
+
+
+class TestKotMutableSubListTypedHelpers(unittest.TestCase):
+    def setUp(self):
+        self.view = KotMutableList([1, 2, 3, 4]).sub_list(0, 3)
+
+    def test_chunking_and_windows_on_view(self):
+        """Chunking and windowing methods work on a sub_list view"""
+        self.assertEqual([c.to_list() for c in self.view.chunked(2)], [[1, 2], [3]])
+        self.assertEqual([w.to_list() for w in self.view.windowed(2)], [[1, 2], [2, 3]])
+        chunks, remainder = self.view.chunked_with_remainder(2)
+        self.assertEqual([c.to_list() for c in chunks], [[1, 2]])
+        self.assertEqual(remainder.to_list(), [3])
+        self.assertEqual(len(self.view.chunked_by(lambda a, b: b == a + 1)), 3)
+        self.assertEqual(self.view.moving_aggregate(2, sum).to_list(), [3, 5])
+
+    def test_reordering_and_splitting_on_view(self):
+        """Methods building typed result lists work on a sub_list view"""
+        self.assertEqual(self.view.rotated(1).to_list(), [3, 1, 2])
+        first, second = self.view.split_at(1)
+        self.assertEqual((first.to_list(), second.to_list()), ([1], [2, 3]))
+        matching, rest = self.view.partition_indexed(lambda i, e: i % 2 == 0)
+        self.assertEqual((matching.to_list(), rest.to_list()), ([1, 3], [2]))
+        self.assertEqual(self.view.take_while_indexed(lambda i, e: i < 2).to_list(), [1, 2])
+        self.assertEqual(self.view.drop_while_indexed(lambda i, e: i < 2).to_list(), [3])
+        self.assertEqual(self.view.prepend(0).to_list(), [0, 1, 2, 3])
+
+    def test_grouping_and_diff_on_view(self):
+        """Grouping and diff work on a sub_list view"""
+        self.assertEqual(self.view.diff([2, 5]).get("removed").to_list(), [1, 3])
+        self.assertEqual(self.view.aggregate_by(lambda x: x % 2, sum).to_dict(), {1: 4, 0: 2})
+        groups = self.view.group_by_mutable(lambda x: x % 2)
+        self.assertEqual(groups.get(1).to_list(), [1, 3])
+
+    def test_view_indices_are_validated(self):
+        """Every index taken by a sub_list view goes through TypeChecker.validate_index"""
+        operations = [
+            lambda: self.view[1.5],
+            lambda: self.view.__setitem__(1.5, 9),
+            lambda: self.view.__delitem__(1.5),
+            lambda: self.view.add_at(1.5, 9),
+            lambda: self.view.set(1.5, 9),
+            lambda: self.view.remove_at(1.5),
+        ]
+        for operation in operations:
+            with self.assertRaisesRegex(TypeError, "Index must be an integer, got 'float'"):
+                operation()
+        self.assertEqual(self.view.to_list(), [1, 2, 3])
+
+    def test_view_reports_parent_element_type(self):
+        """A sub_list view reports the parent's element type and declared flag"""
+        parent = KotMutableList.of_type(int, [1, 2, 3])
+        view = parent.sub_list(0, 2)
+        self.assertEqual(view.element_type, int)
+        self.assertTrue(view.is_type_declared())
+        self.assertTrue(view.to_kot_list().is_type_declared())
@@ -6,6 +6,7 @@ import unittest
 
 from kotcollections.kot_map import KotMap
 from kotcollections.kot_mutable_map import KotMutableMap
+from kotcollections import KotList, KotSet
 
 
 class TestKotMutableMapBasics(unittest.TestCase):
@@ -376,6 +377,18 @@ class TestKotMutableMapTypeResets(unittest.TestCase):
         m.put(1, "string")
         self.assertEqual(m.get(1), "string")
 
+    def test_declared_types_kept_on_clear_and_remove(self):
+        """Test that declared types survive clear and removing the last entry."""
+        m = KotMutableMap.of_type(str, int, {"a": 1})
+        m.clear()
+        with self.assertRaises(TypeError):
+            m.put(1, "x")
+        m.put("b", 2)
+        m.remove("b")
+        with self.assertRaises(TypeError):
+            m.put("c", "x")
+        self.assertEqual(repr(m), "KotMutableMap[str, int]({})")
+
     def test_type_maintained_when_not_empty(self):
         """Test that types are maintained when map is not empty."""
         m = KotMutableMap({"a": 1, "b": 2})
@@ -561,5 +574,186 @@ class TestKotMutableMapTypeSpecification(unittest.TestCase):
         self.assertEqual(len(mutable), 2)
 
 
+class TestKotMutableMapSnapshot(unittest.TestCase):
+    def test_snapshot(self):
+        """snapshot returns an immutable copy that can be iterated while mutating"""
+        m = KotMutableMap({"a": 1, "b": 2})
+        for key in m.snapshot().keys_list():
+            m.remove(key)
+        self.assertTrue(m.is_empty())
+        m.put("c", 3)
+        snap = m.snapshot()
+        self.assertNotIsInstance(snap, KotMutableMap)
+        m.put("d", 4)
+        self.assertEqual(snap.to_dict(), {"c": 3})
+
+
+class TestKotMapConversionTypes(unittest.TestCase):
+    def test_conversion_preserves_declared_types(self):
+        """to_kot_mutable_map and to_kot_map keep both declared types"""
+        source = KotMap.of_type(str, object, {"a": 1})
+        mutable = source.to_kot_mutable_map()
+        self.assertIs(mutable._key_type, str)
+        self.assertIs(mutable._value_type, object)
+        mutable.put("b", "two")
+        with self.assertRaises(TypeError):
+            mutable.put(3, 3)
+        self.assertIs(mutable.to_kot_map()._value_type, object)
+
+    def test_conversion_preserves_single_known_type(self):
+        """A map with only a key type known keeps it through conversion"""
+        source = KotMap({"a": None})
+        self.assertIs(source._key_type, str)
+        self.assertIsNone(source._value_type)
+        mutable = source.to_kot_mutable_map()
+        self.assertIs(mutable._key_type, str)
+        with self.assertRaises(TypeError):
+            mutable.put(1, None)
+        self.assertIs(source.to_kot_map()._key_type, str)
+
+    def test_put_all_from_kot_map(self):
+        """put_all accepts KotMap and KotMutableMap sources"""
+        target = KotMutableMap({"a": 1})
+        target.put_all(KotMap({"b": 2}))
+        target.put_all(KotMutableMap({"a": 3}))
+        self.assertEqual(target.to_dict(), {"a": 3, "b": 2})
+
+
+class TestKotMutableMapComputeCalls(unittest.TestCase):
+    def setUp(self):
+        self.calls = []
+
+    def _record(self, result):
+        def fn(*args):
+            self.calls.append(args)
+            return result
+        return fn
+
+    def test_compute_calls_once(self):
+        """compute invokes the remapping function exactly once"""
+        m = KotMutableMap({"a": 1})
+        self.assertEqual(m.compute("a", self._record(5)), 5)
+        self.assertEqual(m.compute("b", self._record(6)), 6)
+        self.assertEqual(self.calls, [("a", 1), ("b", None)])
+        self.assertIsNone(m.compute("a", lambda k, v: None))
+        self.assertFalse(m.contains_key("a"))
+
+    def test_compute_if_absent_and_present_call_once(self):
+        """compute_if_absent/compute_if_present invoke their function at most once"""
+        m = KotMutableMap({"a": 1})
+        self.assertEqual(m.compute_if_absent("a", self._record(9)), 1)
+        self.assertEqual(m.compute_if_absent("b", self._record(2)), 2)
+        self.assertEqual(m.compute_if_present("a", self._record(3)), 3)
+        self.assertIsNone(m.compute_if_present("z", self._record(4)))
+        self.assertEqual(self.calls, [("b",), ("a", 1)])
+
+    def test_merge_calls_once(self):
+        """merge invokes the remapping function only for existing keys, once"""
+        m = KotMutableMap({"a": 1})
+        self.assertEqual(m.merge("a", 10, self._record(11)), 11)
+        self.assertEqual(m.merge("b", 2, self._record(99)), 2)
+        self.assertEqual(self.calls, [(1, 10)])
+        self.assertEqual(m.to_dict(), {"a": 11, "b": 2})
+
+
+class TestKotMutableMapRemoveRetainKeys(unittest.TestCase):
+    def test_remove_keys(self):
+        """remove_keys removes listed keys in place and returns the count removed"""
+        m = KotMutableMap({"a": 1, "b": 2, "c": 3})
+        self.assertEqual(m.remove_keys(["a", "c", "x"]), 2)
+        self.assertEqual(m.to_dict(), {"b": 2})
+        self.assertEqual(m.remove_keys(KotSet(["x"])), 0)
+
+    def test_retain_keys(self):
+        """retain_keys keeps only listed keys and preserves insertion order"""
+        m = KotMutableMap({"a": 1, "b": 2, "c": 3, "d": 4})
+        self.assertEqual(m.retain_keys(KotList(["d", "b", "x"])), 2)
+        self.assertEqual(list(m.to_dict().items()), [("b", 2), ("d", 4)])
+
+    def test_emptied_map_resets_types(self):
+        """Removing every key resets the inferred key and value types"""
+        m = KotMutableMap({"a": 1})
+        m.retain_keys([])
+        self.assertTrue(m.is_empty())
+        m.put(1, "one")
+        self.assertEqual(m.to_dict(), {1: "one"})
+
+    def test_emptied_map_keeps_declared_types(self):
+        """Removing every key keeps declared key and value types enforced"""
+        m = KotMutableMap.of_type(str, int, {"a": 1})
+        self.assertEqual(m.remove_keys(["a"]), 1)
+        with self.assertRaises(TypeError):
+            m.put(1, "x")
+        m.put("b", 2)
+        self.assertEqual(m.to_dict(), {"b": 2})
+        self.assertEqual(repr(m), "KotMutableMap[str, int]({'b': 2})")
+
+
+class TestKotMutableMapTransformValuesInPlace(unittest.TestCase):
+    def test_transform_values_in_place(self):
+        """transform_values_in_place updates every value and returns None"""
+        m = KotMutableMap({"a": 1, "b": 2})
+        self.assertIsNone(m.transform_values_in_place(lambda v: v * 10))
+        self.assertEqual(m.to_dict(), {"a": 10, "b": 20})
+
+    def test_transform_values_in_place_checks_value_type(self):
+        """transform_values_in_place rejects results that do not match value_type"""
+        m = KotMutableMap.of_type(str, int, {"a": 1})
+        with self.assertRaises(TypeError):
+            m.transform_values_in_place(str)
+
+
+class TestKotMutableMapGetOrPutNullable(unittest.TestCase):
+    def test_get_or_put_skips_default_for_present_none(self):
+        """get_or_put does not call the default for a key mapped to None"""
+        kot_map = KotMutableMap({"a": None})
+        self.assertIsNone(kot_map.get_or_put("a", lambda: self.fail("called")))
+
+    def test_get_or_put_nullable_caches_none(self):
+        """get_or_put_nullable stores a None result and does not recompute it"""
+        calls = []
+
+        def compute():
+            calls.append(1)
+            return None
+
+        kot_map = KotMutableMap()
+        self.assertIsNone(kot_map.get_or_put_nullable("k", compute))
+        self.assertTrue(kot_map.contains_key("k"))
+        self.assertIsNone(kot_map.get_or_put_nullable("k", compute))
+        self.assertEqual(len(calls), 1)
+
+    def test_get_or_put_nullable_absent_vs_present(self):
+        """get_or_put_nullable returns existing values and stores non-None defaults"""
+        kot_map = KotMutableMap({"a": 1})
+        self.assertEqual(kot_map.get_or_put_nullable("a", lambda: 2), 1)
+        self.assertEqual(kot_map.get_or_put_nullable("b", lambda: 3), 3)
+        self.assertEqual(kot_map.to_dict(), {"a": 1, "b": 3})
+
+
+class TestKotMutableMapPutAllSources(unittest.TestCase):
+    def test_put_all_from_read_only_map(self):
+        """put_all copies entries from a read-only KotMap"""
+        kot_map = KotMutableMap({"a": 1})
+        kot_map.put_all(KotMap({"b": 2, "c": 3}))
+        self.assertEqual(kot_map.to_dict(), {"a": 1, "b": 2, "c": 3})
+
+    def test_put_all_from_iterator_of_pairs(self):
+        """put_all accepts any iterable of (key, value) pairs"""
+        kot_map = KotMutableMap()
+        kot_map.put_all(iter([("a", 1), ("b", 2)]))
+        kot_map.put_all((("c", 3),))
+        self.assertEqual(kot_map.to_dict(), {"a": 1, "b": 2, "c": 3})
+
+
+class TestKotMutableMapOfKwargs(unittest.TestCase):
+    def test_of_kwargs_returns_mutable_map(self):
+        """of_kwargs on KotMutableMap returns a mutable map"""
+        kot_map = KotMutableMap.of_kwargs(a=1)
+        self.assertIsInstance(kot_map, KotMutableMap)
+        kot_map.put("b", 2)
+        self.assertEqual(kot_map.to_dict(), {"a": 1, "b": 2})
+
+
 if __name__ == '__main__':
     unittest.main()
\ No newline at end of file
@@ -345,6 +345,18 @@ class TestKotMutableSetTypeManagement(unittest.TestCase):
         self.assertTrue(s.add("world"))
         self.assertEqual(s.size, 2)
 
+    def test_declared_type_kept_on_clear(self):
+        """Test that a declared type survives clear and removing every element."""
+        s = KotMutableSet.of_type(int, [1, 2])
+        s.clear()
+        with self.assertRaises(TypeError):
+            s.add("x")
+        s.add(3)
+        s.remove(3)
+        with self.assertRaises(TypeError):
+            s.add("x")
+        self.assertEqual(repr(s), "KotMutableSet[int]([])")
+
     def test_type_reset_on_remove_all(self):
         """Test that type is reset when all elements are removed."""
         s = KotMutableSet([1, 2, 3])
@@ -702,3 +714,41 @@ class TestKotMutableSetTypeSpecification(unittest.TestCase):
         # Verify we can still add correct types
         mutable_list.add(Dog("Rex"))
         self.assertEqual(len(mutable_list), 2)
+
+
+class TestKotMutableSetSnapshot(unittest.TestCase):
+    def test_snapshot(self):
+        """snapshot returns an immutable copy that can be iterated while mutating"""
+        s = KotMutableSet([1, 2])
+        for x in s.snapshot():
+            s.remove(x)
+        self.assertTrue(s.is_empty())
+        s.add(5)
+        snap = s.snapshot()
+        self.assertNotIsInstance(snap, KotMutableSet)
+        s.add(6)
+        self.assertEqual(snap.to_set(), {5})
+
+
+class TestKotMutableSetDrain(unittest.TestCase):
+    def test_drain_returns_elements_and_clears(self):
+        """drain returns the prior elements as a read-only set and empties the set"""
+        pending = KotMutableSet([1, 2])
+        drained = pending.drain()
+        self.assertEqual(drained.to_set(), {1, 2})
+        self.assertNotIsInstance(drained, KotMutableSet)
+        self.assertTrue(pending.is_empty())
+
+    def test_drain_preserves_element_type(self):
+        """drain keeps the declared element type on the returned set"""
+        drained = KotMutableSet.of_type(str, ["a"]).drain()
+        self.assertTrue(drained.is_type_declared())
+        self.assertEqual(drained._element_type, str)
+
+    def test_drain_keeps_receiver_type(self):
+        """A drained declared set keeps enforcing its element type"""
+        pending = KotMutableSet.of_type(int, [1, 2])
+        pending.drain()
+        with self.assertRaises(TypeError):
+            pending.add("x")
+        self.assertEqual(repr(pending), "KotMutableSet[int]([])")
@@ -2,6 +2,7 @@
 Unit tests for KotSet class.
 """
 
+import random
 import unittest
 
 from kotcollections.kot_set import KotSet
@@ -830,6 +831,13 @@ class TestKotSetNewMethods(unittest.TestCase):
         indices = [i for i, _ in indexed]
         self.assertEqual(sorted(indices), [0, 1, 2])
     
+    def test_with_index_returns_kot_list(self):
+        """with_index returns a KotList of (index, element) pairs like KotMap.with_index"""
+        indexed = KotSet(['a']).with_index()
+        self.assertIsInstance(indexed, KotList)
+        self.assertEqual(indexed.to_list(), [(0, 'a')])
+        self.assertTrue(KotSet().with_index().is_empty())
+    
     def test_zip(self):
         """Test zip method."""
         s1 = KotSet([1, 2, 3])
@@ -1295,5 +1303,318 @@ class TestKotSetTypeSpecification(unittest.TestCase):
         self.assertTrue(lists_set.contains(immutable_persons2))
 
 
+class TestKotSetEmptyOfType(unittest.TestCase):
+    def test_empty_of_type(self):
+        """Test empty_of_type creates an empty set with a fixed element type."""
+        s = KotSet.empty_of_type(int)
+        self.assertTrue(s.is_empty())
+        self.assertEqual(s._element_type, int)
+
+    def test_empty_of_type_mutable_checks_first_add(self):
+        """Test the first add is type-checked instead of inferring the type."""
+        s = KotMutableSet.empty_of_type(int)
+        self.assertIsInstance(s, KotMutableSet)
+        with self.assertRaises(TypeError):
+            s.add("1")
+        self.assertTrue(s.add(1))
+
+
+class TestKotSetRandomTake(unittest.TestCase):
+    def test_random_take(self):
+        """random_take returns n distinct elements from the set"""
+        s = KotSet([1, 2, 3, 4, 5])
+        result = s.random_take(2, random.Random(1))
+        self.assertEqual(result.size, 2)
+        self.assertEqual(result.distinct().size, 2)
+        self.assertTrue(all(s.contains(e) for e in result))
+        with self.assertRaises(ValueError):
+            s.random_take(6)
+        self.assertIsNone(s.random_take_or_null(6))
+        self.assertEqual(set(s.random_take_or_none(5)), {1, 2, 3, 4, 5})
+
+
+class TestKotSetToFrozenset(unittest.TestCase):
+    def test_to_frozenset(self):
+        """to_frozenset returns a hashable frozenset of the elements"""
+        s = KotSet([1, 2, 3])
+        fs = s.to_frozenset()
+        self.assertIsInstance(fs, frozenset)
+        self.assertEqual(fs, frozenset({1, 2, 3}))
+        self.assertEqual({fs: "ok"}[frozenset({3, 2, 1})], "ok")
+        self.assertEqual(KotSet([]).to_frozenset(), frozenset())
+
+    def test_to_frozenset_mutable(self):
+        """to_frozenset on a mutable set is a snapshot"""
+        s = KotMutableSet([1, 2])
+        fs = s.to_frozenset()
+        s.add(3)
+        self.assertEqual(fs, frozenset({1, 2}))
+
+
+class TestKotSetFormat(unittest.TestCase):
+    def test_format(self):
+        """Format specs join the set elements; an empty spec falls back to str()"""
+        s = KotSet([7])
+        self.assertEqual(f"{s:, }", "7")
+        self.assertEqual(sorted(f"{KotSet([1, 2]):,}".split(",")), ["1", "2"])
+        self.assertEqual(f"{s}", str(s))
+
+
+class TestKotSetToKotMap(unittest.TestCase):
+    def test_to_kot_map(self):
+        """A set of pairs converts to a map"""
+        s = KotSet([("a", 1), ("b", 2)])
+        self.assertEqual(s.to_kot_map().to_dict(), {"a": 1, "b": 2})
+        self.assertEqual(s.to_kot_map_of_types(str, int).size, 2)
+        with self.assertRaises(ValueError):
+            KotSet([(1,)]).to_kot_map()
+
+
+class TestKotSetOrdering(unittest.TestCase):
+    def test_to_sorted_list(self):
+        """to_sorted_list sorts naturally or with a comparator"""
+        s = KotSet([3, 1, 2])
+        self.assertEqual(s.to_sorted_list().to_list(), [1, 2, 3])
+        self.assertEqual(s.to_sorted_list(lambda a, b: b - a).to_list(), [3, 2, 1])
+        self.assertEqual(KotSet.of_type(int, [2, 1]).to_sorted_list().element_type, int)
+        self.assertTrue(KotSet().to_sorted_list().is_empty())
+
+    def test_comparison_operators(self):
+        """Sets compare lexicographically by their sorted elements"""
+        a = KotSet([3, 1])
+        b = KotSet([2, 1])
+        self.assertTrue(b < a)
+        self.assertTrue(a > b)
+        self.assertTrue(a <= KotSet([1, 3]))
+        self.assertTrue(a >= KotSet([1, 3]))
+        self.assertTrue(KotSet([1]) < KotSet([1, 2]))
+        self.assertTrue(KotSet() < KotSet([0]))
+
+    def test_sets_of_sets_sort_deterministically(self):
+        """Sets of sets can be put into a canonical order"""
+        sets = [KotSet([2, 3]), KotSet([1, 5]), KotSet([1, 2])]
+        self.assertEqual([s.to_sorted_list().to_list() for s in sorted(sets)], [[1, 2], [1, 5], [2, 3]])
+
+    def test_comparison_with_non_sets(self):
+        """Comparing with a non-KotSet is not supported"""
+        with self.assertRaises(TypeError):
+            KotSet([1]) < [1]
+        with self.assertRaises(TypeError):
+            KotSet([1]) >= {1}
+
+
+class TestKotSetCountDistinct(unittest.TestCase):
+    def test_count_distinct(self):
+        """count_distinct equals the size; count_distinct_by counts unique keys"""
+        s = KotSet([1, 2, 3, 4])
+        self.assertEqual(s.count_distinct(), 4)
+        self.assertEqual(s.count_distinct_by(lambda x: x % 2), 2)
+        self.assertEqual(KotSet().count_distinct_by(lambda x: x), 0)
+
+
+class TestKotSetAssociateWithKeyType(unittest.TestCase):
+    def test_key_type_follows_element_type(self):
+        """The resulting map uses the set's element type as its key type"""
+        result = KotSet.of_type(object, [1, "a"]).associate_with(str)
+        self.assertIs(result._key_type, object)
+        self.assertEqual(result.get(1), "1")
+
+
+class TestKotSetReduceOrElse(unittest.TestCase):
+    def test_reduce_or_else(self):
+        """reduce_or_else reduces non-empty sets and falls back on empty ones"""
+        self.assertEqual(KotSet([1, 2, 3]).reduce_or_else(lambda a, b: a + b, lambda: 0), 6)
+        self.assertEqual(KotMutableSet().reduce_or_else(lambda a, b: a + b, lambda: 0), 0)
+
+
+class TestKotSetExtremeTieBreaker(unittest.TestCase):
+    def test_tie_breaker(self):
+        """The tie breaker makes extreme selection deterministic for sets"""
+        s = KotSet(["bb", "aa", "cc", "d"])
+        self.assertEqual(s.max_by_or_null(len, tie_breaker=lambda x: x), "cc")
+        self.assertEqual(s.min_by_or_none(lambda x: -len(x), lambda x: x), "aa")
+
+
+class TestKotSetExactSums(unittest.TestCase):
+    def test_decimal_sums_stay_exact(self):
+        """sum and sum_of keep Decimal precision"""
+        from decimal import Decimal
+        s = KotSet([Decimal("0.1"), Decimal("0.2")])
+        self.assertEqual(s.sum(), Decimal("0.3"))
+        self.assertEqual(s.sum_of(lambda d: d), Decimal("0.3"))
+        self.assertEqual(KotSet().sum(), 0)
+
+
+class TestKotSetGroupByKeys(unittest.TestCase):
+    def test_unhashable_keys_raise_clear_error(self):
+        """Unhashable group_by keys raise a clear TypeError"""
+        with self.assertRaises(TypeError) as cm:
+            KotSet([1, 2]).group_by(lambda x: [x])
+        self.assertIn("must be hashable", str(cm.exception))
+        with self.assertRaises(TypeError):
+            KotSet([1]).group_by_to(lambda x: {}, lambda x: x)
+
+
+class TestKotSetIsInstanceAccess(unittest.TestCase):
+    def test_is_instance_access(self):
+        """Type-filtered first/last access on sets"""
+        s = KotSet.of_type(object, [1, "a"])
+        self.assertEqual(s.first_is_instance(str), "a")
+        self.assertEqual(s.last_is_instance(int), 1)
+        self.assertIsNone(s.first_is_instance_or_none(float))
+        with self.assertRaises(ValueError):
+            s.first_is_instance(float)
+        with self.assertRaises(ValueError):
+            s.last_is_instance(float)
+
+    def test_last_is_instance_or_null(self):
+        """last_is_instance_or_null/_or_none return the match or None"""
+        s = KotSet.of_type(object, [1, "a"])
+        self.assertEqual(s.last_is_instance_or_null(str), "a")
+        self.assertEqual(s.last_is_instance_or_none(int), 1)
+        self.assertIsNone(s.last_is_instance_or_null(float))
+
+    def test_first_is_instance_finds_none_element(self):
+        """first_is_instance finds a None element instead of treating it as missing"""
+        s = KotSet.of_type(object, [None, 1])
+        self.assertIsNone(s.first_is_instance(type(None)))
+
+
+class TestKotSetCountBy(unittest.TestCase):
+    def test_count_by(self):
+        """count_by tallies set elements by key, or counts each element once without a selector"""
+        s = KotSet([1, 2, 3, 4, 5])
+        self.assertEqual(s.count_by(lambda x: x % 2 == 0).to_dict(), {False: 3, True: 2})
+        self.assertEqual(s.count_by().to_dict(), {1: 1, 2: 1, 3: 1, 4: 1, 5: 1})
+
+
+class TestKotSetMostCommon(unittest.TestCase):
+    def test_most_common(self):
+        """most_common on a set reports every element with a count of one"""
+        result = KotSet(["x", "y"]).most_common()
+        self.assertEqual(sorted(result.to_list()), [("x", 1), ("y", 1)])
+        self.assertEqual(KotSet([1, 2, 3]).most_common(1).size, 1)
+
+
+class TestKotSetToCounter(unittest.TestCase):
+    def test_to_counter(self):
+        """to_counter on a set counts selector keys across elements"""
+        from collections import Counter
+        s = KotSet([1, 2, 3, 4])
+        self.assertEqual(s.to_counter(), Counter({1: 1, 2: 1, 3: 1, 4: 1}))
+        self.assertEqual(s.to_counter(lambda x: x % 2), Counter({0: 2, 1: 2}))
+
+
+class TestKotSetAverageOrNull(unittest.TestCase):
+    def test_average_or_null(self):
+        """average_or_null averages selector values, or returns None for an empty set"""
+        self.assertEqual(KotSet([1, 2, 3]).average_or_null(lambda x: x * 2), 4.0)
+        self.assertIsNone(KotSet().average_or_null(lambda x: x))
+        self.assertIsNone(KotSet().average_or_none(lambda x: x))
+
+
+class TestKotSetVariance(unittest.TestCase):
+    def test_variance(self):
+        """variance and standard_deviation work over set elements"""
+        s = KotSet([1, 2, 3, 4])
+        self.assertEqual(s.variance(), 1.25)
+        self.assertAlmostEqual(s.standard_deviation(sample=True), (5 / 3) ** 0.5)
+        with self.assertRaises(ValueError):
+            KotSet().variance()
+
+
+class TestKotSetIfEmptyIfNotEmpty(unittest.TestCase):
+    def test_if_empty_and_if_not_empty(self):
+        """if_empty and if_not_empty pick between the set and the default value"""
+        s = KotSet([1])
+        self.assertIs(s.if_empty(KotSet([2])), s)
+        self.assertEqual(KotSet().if_empty(lambda: KotSet([2])), KotSet([2]))
+        self.assertEqual(s.if_not_empty(KotSet()), KotSet())
+        self.assertTrue(KotSet().if_not_empty(lambda: KotSet([1])).is_empty())
+
+
+class TestKotSetOrEmpty(unittest.TestCase):
+    def test_or_empty(self):
+        """or_empty maps None to an empty set, keeps KotSets and wraps iterables"""
+        self.assertTrue(KotSet.or_empty(None).is_empty())
+        s = KotSet([1])
+        self.assertIs(KotSet.or_empty(s), s)
+        self.assertEqual(KotSet.or_empty([1, 1, 2]), KotSet([1, 2]))
+
+
+class TestKotSetJoinTo(unittest.TestCase):
+    def test_join_to(self):
+        """join_to writes the joined set elements into the destination"""
+        import io
+        buffer = KotSet([1]).join_to(io.StringIO(), prefix="{", postfix="}")
+        self.assertEqual(buffer.getvalue(), "{1}")
+        self.assertEqual(KotSet([1, 2, 3]).join_to(io.StringIO(), limit=0).getvalue(), "...")
+
+
+class TestKotSetEqualsNative(unittest.TestCase):
+    def test_equals_native(self):
+        """equals_native compares a set against a native set"""
+        self.assertTrue(KotSet([1, 2]).equals_native({1, 2}))
+        self.assertTrue(KotSet().equals_native(set()))
+        self.assertFalse(KotSet([1, 2]).equals_native([1, 2]))
+        self.assertFalse(KotSet([1, 2]).equals_native({1, 3}))
+
+
+class TestKotSetDeclaredTypeRepr(unittest.TestCase):
+    def test_repr_shows_declared_type(self):
+        """repr includes the element type only when it was declared"""
+        from kotcollections import KotMutableSet
+        self.assertEqual(repr(KotSet.of_type(int, [1])), "KotSet[int]([1])")
+        self.assertEqual(repr(KotSet([1])), "KotSet([1])")
+        self.assertEqual(repr(KotSet([1]).to_kot_list()), "KotList([1])")
+        self.assertEqual(repr(KotMutableSet.of_type(int, [1])), "KotMutableSet[int]([1])")
+
+
+class TestKotSetIsTypeDeclared(unittest.TestCase):
+    def test_is_type_declared(self):
+        """is_type_declared is true only for sets created with a declared element type"""
+        from kotcollections import KotMutableSet
+        self.assertTrue(KotSet.of_type(int, [1]).is_type_declared())
+        self.assertTrue(KotMutableSet[int]().is_type_declared())
+        self.assertFalse(KotSet([1]).is_type_declared())
+        self.assertFalse(KotMutableSet([1]).is_type_declared())
+
+
+class TestKotSetContainsAny(unittest.TestCase):
+    def test_contains_any(self):
+        """contains_any checks membership for any element, skipping unhashable ones"""
+        s = KotSet([1, 2, 3])
+        self.assertTrue(s.contains_any(KotList([9, 3])))
+        self.assertFalse(s.contains_any([[1], 7]))
+        self.assertFalse(KotMutableSet().contains_any([1]))
+        self.assertTrue(KotMutableSet(["a"]).contains_any(iter(["b", "a"])))
+
+
+class TestKotSetUnionAll(unittest.TestCase):
+    def test_union_all(self):
+        """union_all deduplicates elements across all sources"""
+        result = KotSet.union_all([KotSet([1, 2]), [2, 3], iter((3, 4))])
+        self.assertEqual(result, KotSet([1, 2, 3, 4]))
+        self.assertTrue(KotSet.union_all([]).is_empty())
+
+    def test_union_all_element_type(self):
+        """union_all keeps the declared element type when all sources agree"""
+        result = KotMutableSet.union_all([KotSet.of_type(int, [1]), KotList.of_type(int, [2])])
+        self.assertIsInstance(result, KotMutableSet)
+        self.assertEqual(result._element_type, int)
+        self.assertTrue(result.is_type_declared())
+        mixed = KotSet.union_all([KotSet.of_type(object, [1]), KotSet.of_type(int, [2])])
+        self.assertFalse(mixed.is_type_declared())
+
+
+class TestKotSetOfTypeValidation(unittest.TestCase):
+    def test_of_type_rejects_non_type(self):
+        """of_type raises TypeError when element_type is not a type"""
+        with self.assertRaises(TypeError):
+            KotSet.of_type(1)
+        with self.assertRaises(TypeError):
+            KotMutableSet.of_type("str", ["a"])
+
+
 if __name__ == '__main__':
     unittest.main()